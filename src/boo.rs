@@ -4,7 +4,7 @@
 #![cfg(feature = "boo")]
 //! A module for Boo (Borrow or Owned)
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     ops::{Deref, DerefMut},
 };
 
@@ -36,6 +36,7 @@ impl<'b, T> TryFrom<Mob<'b, T>> for Boo<'b, T> {
     }
 }
 
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> AsRef<T> for Boo<'b, T> {
     fn as_ref(&self) -> &T {
         match self {
@@ -44,6 +45,7 @@ impl<'b, T> AsRef<T> for Boo<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Borrow<T> for Boo<'b, T> {
     fn borrow(&self) -> &T {
         match self {
@@ -52,6 +54,7 @@ impl<'b, T> Borrow<T> for Boo<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Deref for Boo<'b, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -62,6 +65,16 @@ impl<'b, T> Deref for Boo<'b, T> {
     }
 }
 
+/// clones the owned value, which may allocate, but only copies the reference when borrowed
+impl<T: Clone> Clone for Boo<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Borrowed(t) => Self::Borrowed(t),
+            Self::Owned(t) => Self::Owned(t.clone()),
+        }
+    }
+}
+
 impl<'b, T> Boo<'b, T> {
     /// creates a new instance while coercing mutable references to normal refs
     pub fn from_coerce_ref(value: impl TryInto<Self, Error = &'b mut T>) -> Self {
@@ -88,6 +101,34 @@ impl<'b, T> Boo<'b, T> {
     {
         self.into_owned(|it| *it)
     }
+    /// gives the owned instance of `T` or `default` when borrowed
+    pub fn unwrap_or(self, default: T) -> T {
+        self.into_owned(|_| default)
+    }
+    /// gives the owned instance of `T` or `T::default()` when borrowed
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.into_owned(|_| T::default())
+    }
+    /// keeps the owned instance of `T` or substitutes the borrow with `fallback`
+    pub fn or_borrowed(self, fallback: &'b T) -> Self {
+        match self {
+            Self::Owned(t) => Self::Owned(t),
+            Self::Borrowed(_) => Self::Borrowed(fallback),
+        }
+    }
+    /// converts into a [`Cow`] with the same ownership
+    pub fn into_cow(self) -> Cow<'b, T>
+    where
+        T: ToOwned<Owned = T>,
+    {
+        match self {
+            Self::Owned(t) => Cow::Owned(t),
+            Self::Borrowed(t) => Cow::Borrowed(t),
+        }
+    }
 }
 
 /// A Mutable, Owned or Borrowed Smart Pointer
@@ -119,6 +160,14 @@ impl<'b, T> From<Boo<'b, T>> for Mob<'b, T> {
         }
     }
 }
+impl<'b, T: Clone> From<Cow<'b, T>> for Mob<'b, T> {
+    fn from(value: Cow<'b, T>) -> Self {
+        match value {
+            Cow::Owned(owned) => Mob::Owned(owned),
+            Cow::Borrowed(borrow) => Mob::Borrowed(borrow),
+        }
+    }
+}
 
 // From Mob
 impl<'b, T> From<Mob<'b, T>> for Option<&'b mut T> {
@@ -126,7 +175,19 @@ impl<'b, T> From<Mob<'b, T>> for Option<&'b mut T> {
         val.try_into_mut()
     }
 }
+impl<'b, T: Clone> TryFrom<Mob<'b, T>> for Cow<'b, T> {
+    type Error = &'b mut T;
 
+    fn try_from(value: Mob<'b, T>) -> Result<Self, Self::Error> {
+        match value {
+            Mob::BorrowedMut(t) => Err(t),
+            Mob::Borrowed(t) => Ok(Self::Borrowed(t)),
+            Mob::Owned(t) => Ok(Self::Owned(t)),
+        }
+    }
+}
+
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> AsRef<T> for Mob<'b, T> {
     fn as_ref(&self) -> &T {
         match self {
@@ -136,6 +197,7 @@ impl<'b, T> AsRef<T> for Mob<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Borrow<T> for Mob<'b, T> {
     fn borrow(&self) -> &T {
         match self {
@@ -145,6 +207,7 @@ impl<'b, T> Borrow<T> for Mob<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Deref for Mob<'b, T> {
     type Target = T;
 
@@ -159,7 +222,7 @@ impl<'b, T> Deref for Mob<'b, T> {
 
 impl<'b, T> Mob<'b, T> {
     /// tries to give a mut ref to the held data where possible (owned, borrow mut) and None else (borrowed)
-    pub fn try_as_mut(&mut self) -> Option<&mut T> {
+    pub const fn try_as_mut(&mut self) -> Option<&mut T> {
         match self {
             Self::Owned(t) => Some(t),
             Self::BorrowedMut(t) => Some(t),
@@ -173,6 +236,20 @@ impl<'b, T> Mob<'b, T> {
             Self::Borrowed(_) | Mob::Owned(_) => None,
         }
     }
+    /// gives a mut ref to the held data, a borrowed value will be cloned into an owned one first
+    pub fn to_mut(&mut self) -> &mut T
+    where
+        T: Clone,
+    {
+        if let Self::Borrowed(t) = *self {
+            *self = Self::Owned(t.clone());
+        }
+        match self {
+            Self::Owned(t) => t,
+            Self::BorrowedMut(t) => t,
+            Self::Borrowed(_) => unreachable!("borrowed data was replaced"),
+        }
+    }
 
     /// gives an owned instance of `T` by using `deref` on the held reference
     pub fn into_owned(self, deref: impl FnOnce(&'b T) -> T) -> T {
@@ -208,6 +285,31 @@ impl<'b, T> Mob<'b, T> {
             _ => (self.cloned(), None),
         }
     }
+    /// applies `op_assign` to `lhs` and `rhs`, reusing the storage of `lhs` when it is owned or mutably borrowed
+    ///
+    /// a borrowed `lhs` is cloned first, so shared data is never mutated
+    #[allow(clippy::needless_pass_by_value)] // takes any ownership of `rhs` like `lhs`
+    pub fn binop(lhs: Self, rhs: Mob<'_, T>, op_assign: impl FnOnce(&mut T, &T)) -> Moo<'b, T>
+    where
+        T: Clone,
+    {
+        let mut result = Moo::from_mob_cloned(lhs);
+        op_assign(&mut result, &rhs);
+        result
+    }
+    /// converts into a [`Cow`] with the same ownership
+    ///
+    /// a mutable borrow is kept as a shared borrow, so nothing will be cloned
+    pub fn into_cow(self) -> Cow<'b, T>
+    where
+        T: ToOwned<Owned = T>,
+    {
+        match self {
+            Self::Owned(t) => Cow::Owned(t),
+            Self::Borrowed(t) => Cow::Borrowed(t),
+            Self::BorrowedMut(t) => Cow::Borrowed(t),
+        }
+    }
 }
 
 /// A Mutable or Owned Smart Pointer
@@ -246,6 +348,7 @@ impl<'b, T> From<Moo<'b, T>> for Option<&'b mut T> {
     }
 }
 
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> AsRef<T> for Moo<'b, T> {
     fn as_ref(&self) -> &T {
         match self {
@@ -254,6 +357,7 @@ impl<'b, T> AsRef<T> for Moo<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Borrow<T> for Moo<'b, T> {
     fn borrow(&self) -> &T {
         match self {
@@ -262,6 +366,7 @@ impl<'b, T> Borrow<T> for Moo<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> Deref for Moo<'b, T> {
     type Target = T;
 
@@ -272,6 +377,7 @@ impl<'b, T> Deref for Moo<'b, T> {
         }
     }
 }
+#[allow(clippy::elidable_lifetime_names)]
 impl<'b, T> DerefMut for Moo<'b, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         match self {
@@ -292,6 +398,20 @@ impl<'b, T> Moo<'b, T> {
             None => Self::Owned(value),
         }
     }
+    /// like [`Self::from_with_value`], but the value is computed by `f`
+    pub fn from_with_value_else(
+        maybe_ref: impl Into<Option<&'b mut T>>,
+        f: impl FnOnce() -> T,
+    ) -> Self {
+        Self::from_with_value(maybe_ref, f())
+    }
+    /// like [`Self::from_with_value`], but always with `T::default()`
+    pub fn from_ref_or_default(maybe_ref: impl Into<Option<&'b mut T>>) -> Self
+    where
+        T: Default,
+    {
+        Self::from_with_value_else(maybe_ref, T::default)
+    }
     /// creates a new insatance from `value` with the potetionally borrowed value cloned
     pub fn from_mob_cloned(value: Mob<'b, T>) -> Self
     where
@@ -352,4 +472,228 @@ impl<'b, T> Moo<'b, T> {
     {
         self.into_owned(|it| *it)
     }
+    /// converts into a [`Cow`] with the same ownership, see [`Mob::into_cow`]
+    pub fn into_cow(self) -> Cow<'b, T>
+    where
+        T: ToOwned<Owned = T>,
+    {
+        Mob::<T>::from(self).into_cow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Debug)]
+    struct CloneCounter<'a>(u8, &'a Cell<usize>);
+    impl Clone for CloneCounter<'_> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Self(self.0, self.1)
+        }
+    }
+
+    #[test]
+    fn boo_fallback() {
+        let borrowed = String::from("borrowed");
+        let fallback = String::from("fallback");
+
+        assert_eq!(
+            "owned",
+            Boo::Owned(String::from("owned")).unwrap_or(String::from("default"))
+        );
+        assert_eq!(
+            "default",
+            Boo::Borrowed(&borrowed).unwrap_or(String::from("default"))
+        );
+
+        assert_eq!(
+            "owned",
+            Boo::Owned(String::from("owned")).unwrap_or_default()
+        );
+        assert_eq!("", Boo::Borrowed(&borrowed).unwrap_or_default());
+
+        assert!(matches!(
+            Boo::Owned(String::from("owned")).or_borrowed(&fallback),
+            Boo::Owned(it) if it == "owned"
+        ));
+        assert!(matches!(
+            Boo::Borrowed(&borrowed).or_borrowed(&fallback),
+            Boo::Borrowed(it) if std::ptr::eq(it, &raw const fallback)
+        ));
+    }
+
+    #[test]
+    fn boo_clone() {
+        let clones = Cell::new(0);
+        let data = CloneCounter(1, &clones);
+
+        let borrowed = Boo::Borrowed(&data);
+        let copy = borrowed.clone();
+        assert_eq!(0, clones.get(), "cloned borrowed data");
+        assert!(
+            matches!(copy, Boo::Borrowed(it) if std::ptr::eq(it, &raw const data)),
+            "didn't keep the borrow"
+        );
+
+        let owned = Boo::Owned(CloneCounter(2, &clones));
+        let copy = owned.clone();
+        assert_eq!(1, clones.get(), "didn't clone owned data");
+        assert!(matches!(copy, Boo::Owned(CloneCounter(2, _))));
+    }
+
+    #[test]
+    fn mob_cow() {
+        let data = 1;
+        assert_eq!(Mob::Owned(2), Mob::from(Cow::Owned(2)), "failed owned");
+        assert!(
+            matches!(Mob::from(Cow::Borrowed(&data)), Mob::Borrowed(it) if std::ptr::eq(it, &raw const data)),
+            "failed borrowed"
+        );
+
+        assert!(
+            matches!(Cow::try_from(Mob::Owned(2)), Ok(Cow::Owned(2))),
+            "failed owned into cow"
+        );
+        assert!(
+            matches!(Cow::try_from(Mob::Borrowed(&data)), Ok(Cow::Borrowed(1))),
+            "failed borrowed into cow"
+        );
+        let mut data = 1;
+        assert!(
+            matches!(Cow::try_from(Mob::BorrowedMut(&mut data)), Err(1)),
+            "converted mutable borrow into cow"
+        );
+    }
+
+    #[test]
+    fn into_cow() {
+        let data = 1;
+        assert!(
+            matches!(Boo::Owned(2).into_cow(), Cow::Owned(2)),
+            "failed owned boo"
+        );
+        assert!(
+            matches!(Boo::Borrowed(&data).into_cow(), Cow::Borrowed(it) if std::ptr::eq(it, &raw const data)),
+            "failed borrowed boo"
+        );
+
+        assert!(
+            matches!(Mob::Owned(2).into_cow(), Cow::Owned(2)),
+            "failed owned mob"
+        );
+        assert!(
+            matches!(Mob::Borrowed(&data).into_cow(), Cow::Borrowed(it) if std::ptr::eq(it, &raw const data)),
+            "failed borrowed mob"
+        );
+        let mut data = 1;
+        let ptr = &raw const data;
+        assert!(
+            matches!(Mob::BorrowedMut(&mut data).into_cow(), Cow::Borrowed(it) if std::ptr::eq(it, ptr)),
+            "failed mutable borrowed mob"
+        );
+
+        assert!(
+            matches!(Moo::Owned(2).into_cow(), Cow::Owned(2)),
+            "failed owned moo"
+        );
+        assert!(
+            matches!(Moo::BorrowedMut(&mut data).into_cow(), Cow::Borrowed(it) if std::ptr::eq(it, ptr)),
+            "failed mutable borrowed moo"
+        );
+    }
+
+    #[test]
+    fn moo_from_value() {
+        let mut data = 1;
+        assert!(
+            matches!(
+                Moo::from_with_value_else(&mut data, || 2),
+                Moo::BorrowedMut(2)
+            ),
+            "failed borrowed with closure"
+        );
+        assert_eq!(2, data, "didn't write into the ref");
+        assert_eq!(
+            Moo::Owned(3),
+            Moo::from_with_value_else(None, || 3),
+            "failed owned with closure"
+        );
+
+        assert!(
+            matches!(Moo::from_ref_or_default(&mut data), Moo::BorrowedMut(0)),
+            "failed borrowed default"
+        );
+        assert_eq!(0, data, "didn't write default into the ref");
+        assert_eq!(
+            Moo::Owned(0),
+            Moo::<u8>::from_ref_or_default(None),
+            "failed owned default"
+        );
+    }
+
+    #[test]
+    fn mob_binop() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Kind {
+            Owned,
+            Borrowed,
+            BorrowedMut,
+        }
+        fn make(kind: Kind, value: &mut i32) -> Mob<'_, i32> {
+            match kind {
+                Kind::Owned => Mob::Owned(*value),
+                Kind::Borrowed => Mob::Borrowed(value),
+                Kind::BorrowedMut => Mob::BorrowedMut(value),
+            }
+        }
+        const KINDS: [Kind; 3] = [Kind::Owned, Kind::Borrowed, Kind::BorrowedMut];
+
+        for lhs_kind in KINDS {
+            for rhs_kind in KINDS {
+                let (mut lhs, mut rhs) = (1, 2);
+                let result = Mob::binop(
+                    make(lhs_kind, &mut lhs),
+                    make(rhs_kind, &mut rhs),
+                    |a, b| {
+                        *a += b;
+                    },
+                );
+                assert_eq!(3, *result, "wrong result for {lhs_kind:?} and {rhs_kind:?}");
+                let is_borrowed = matches!(result, Moo::BorrowedMut(_));
+                assert_eq!(
+                    lhs_kind == Kind::BorrowedMut,
+                    is_borrowed,
+                    "wrong storage for {lhs_kind:?} and {rhs_kind:?}"
+                );
+                assert_eq!(
+                    if lhs_kind == Kind::BorrowedMut { 3 } else { 1 },
+                    lhs,
+                    "wrong lhs for {lhs_kind:?} and {rhs_kind:?}"
+                );
+                assert_eq!(2, rhs, "changed rhs for {lhs_kind:?} and {rhs_kind:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn mob_to_mut() {
+        let clones = Cell::new(0);
+        let data = CloneCounter(1, &clones);
+
+        let mut mob = Mob::Borrowed(&data);
+        mob.to_mut().0 = 2;
+        assert_eq!(1, clones.get(), "didn't clone borrowed data");
+        mob.to_mut().0 += 1;
+        assert_eq!(1, clones.get(), "cloned owned data again");
+        assert!(matches!(mob, Mob::Owned(CloneCounter(3, _))));
+        assert_eq!(1, data.0, "changed borrowed data");
+
+        let mut data = CloneCounter(1, &clones);
+        Mob::BorrowedMut(&mut data).to_mut().0 = 2;
+        assert_eq!(1, clones.get(), "cloned mutable borrowed data");
+        assert_eq!(2, data.0, "didn't change mutable borrowed data");
+    }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: MPL-2.0
 
 use itertools::Itertools;
+use thiserror::Error;
 
 macro_rules! const_for {
     ($var: ident, $max: expr, $block: block) => {
@@ -12,6 +13,14 @@ macro_rules! const_for {
         }
     };
 }
+/// an error denoting that a value has set bits, that don't fit into a [`BitSet`]
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("{value:#x} doesn't fit into {bits} bits")]
+pub struct TooWide {
+    value: u64,
+    bits: usize,
+}
+
 /// Holds packed bits and manages access to them
 ///
 /// From<uint> will index lowest to highest bit
@@ -113,6 +122,69 @@ impl<const BYTES: usize> BitSet<BYTES> {
     pub const fn new(bytes: [u8; BYTES]) -> Self {
         Self { bytes }
     }
+    /// creates a new bitset from little endian `bytes`
+    /// returns `None` if `bytes` doesn't have exactly `BYTES` elements
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(Self::new)
+    }
+    /// creates a new bitset from the lowest `BYTES * 8` bits of `value`
+    ///
+    /// # Errors
+    /// returns [`TooWide`] when `value` has a set bit beyond the first `BYTES * 8`
+    pub const fn try_from_u64(value: u64) -> Result<Self, TooWide> {
+        if BYTES < 8 && value >> (BYTES * 8) != 0 {
+            return Err(TooWide {
+                value,
+                bits: BYTES * 8,
+            });
+        }
+        let le_bytes = value.to_le_bytes();
+        let mut bytes = [0; BYTES];
+        const_for!(i, if BYTES < 8 { BYTES } else { 8 }, {
+            bytes[i] = le_bytes[i];
+        });
+        Ok(Self::new(bytes))
+    }
+    /// creates a new bitset from `bits`, lowest bit first
+    /// any bits after the first `BYTES * 8` are ignored
+    pub fn from_bools(bits: impl IntoIterator<Item = bool>) -> Self {
+        let mut this = Self::default();
+        for (i, bit) in bits.into_iter().take(BYTES * 8).enumerate() {
+            this.set(i, bit);
+        }
+        this
+    }
+    /// creates a new bitset with `lo` in the low bytes and `hi` in the high bytes
+    /// fails to compile if `BYTES` isn't `A + B`
+    pub const fn concat<const A: usize, const B: usize>(lo: BitSet<A>, hi: BitSet<B>) -> Self {
+        const { assert!(BYTES == A + B, "BYTES needs to be A + B") };
+        let mut bytes = [0; BYTES];
+        const_for!(i, A, {
+            bytes[i] = lo.bytes[i];
+        });
+        const_for!(i, B, {
+            bytes[A + i] = hi.bytes[i];
+        });
+        Self::new(bytes)
+    }
+    /// copies `self` into a set with `NEW` bytes
+    ///
+    /// growing pads the high bytes with zeros, shrinking drops the high bytes and loses thier bits
+    pub const fn resize<const NEW: usize>(&self) -> BitSet<NEW> {
+        let mut bytes = [0; NEW];
+        const_for!(i, if NEW < BYTES { NEW } else { BYTES }, {
+            bytes[i] = self.bytes[i];
+        });
+        BitSet::new(bytes)
+    }
+    /// creates a new bitset with all bits set
+    pub const fn all_set() -> Self {
+        Self::new([0xFF; BYTES])
+    }
+    /// returns the little endian bytes of `self`
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
 
     const fn split_index(index: usize) -> (usize, usize) {
         assert!(Self::is_in_bounds(index), "index out of bounds");
@@ -129,8 +201,48 @@ impl<const BYTES: usize> BitSet<BYTES> {
         self.bytes[byte_index] & (1 << bit_index) != 0
     }
 
+    /// copies the `OUT * 8` bits starting at position `start` into a new `BitSet`
+    ///
+    /// # Panics
+    /// will panic when the range exceeds the bounds of `self`
+    pub fn sub<const OUT: usize>(&self, start: usize) -> BitSet<OUT> {
+        assert!(
+            start + OUT * 8 <= BYTES * 8,
+            "range {start}..{} out of bounds",
+            start + OUT * 8
+        );
+        let mut out = BitSet::<OUT>::default();
+        if start.is_multiple_of(8) {
+            out.bytes
+                .copy_from_slice(&self.bytes[start / 8..start / 8 + OUT]);
+        } else {
+            for i in 0..OUT * 8 {
+                out.set(i, self.get(start + i));
+            }
+        }
+        out
+    }
+
+    /// iterates over all bits together with thier index
+    pub fn indexed(self) -> std::iter::Enumerate<IterWrapper<BYTES>> {
+        self.into_iter().enumerate()
+    }
+    /// iterates over all bits of a copy of `self` together with thier index
+    pub fn iter_indexed(&self) -> std::iter::Enumerate<IterWrapper<BYTES>> {
+        self.indexed()
+    }
+    /// lists the indices of all set bits like `{0, 1, 12}`
+    pub fn to_index_string(&self) -> String {
+        format!(
+            "{{{}}}",
+            self.iter_indexed()
+                .filter_map(|(i, bit)| bit.then_some(i))
+                .join(", ")
+        )
+    }
+
     /// flips the the bit at position `index`
-    pub fn flip(&mut self, index: usize) {
+    pub const fn flip(&mut self, index: usize) {
         let (bit_index, byte_index) = Self::split_index(index);
         self.bytes[byte_index] ^= 1 << bit_index;
     }
@@ -155,10 +267,18 @@ impl<const BYTES: usize> BitSet<BYTES> {
         });
         count
     }
+    /// is an odd number of bits set, the xor of all bits
+    pub const fn parity(&self) -> bool {
+        self.count() % 2 == 1
+    }
+    /// is exactly one bit set
+    pub const fn is_single_bit(&self) -> bool {
+        self.count() == 1
+    }
     /// are all bits set
     pub const fn all(&self) -> bool {
         const_for!(i, BYTES, {
-            if self.bytes[i] == 0xFF {
+            if self.bytes[i] != 0xFF {
                 return false;
             }
         });
@@ -179,9 +299,13 @@ impl<const BYTES: usize> BitSet<BYTES> {
     }
 
     /// sets all bits to false
-    pub fn clear(&mut self) {
+    pub const fn clear(&mut self) {
         self.bytes = [0; BYTES];
     }
+    /// sets all bits to true
+    pub const fn set_all(&mut self) {
+        self.bytes = [0xFF; BYTES];
+    }
 
     /// calculates the union between `self` and `other`
     pub const fn union(&self, other: &Self) -> Self {
@@ -199,6 +323,58 @@ impl<const BYTES: usize> BitSet<BYTES> {
         });
         Self::new(data)
     }
+    /// overwrites only the bits of `self` where `mask` is set with the bits of `value`
+    pub const fn apply_masked(&mut self, value: &Self, mask: &Self) {
+        const_for!(i, BYTES, {
+            self.bytes[i] = (self.bytes[i] & !mask.bytes[i]) | (value.bytes[i] & mask.bytes[i]);
+        });
+    }
+    /// mirrors all bits, so that the lowest bit becomes the highest
+    pub const fn reverse_bits(self) -> Self {
+        let mut data = [0; BYTES];
+        const_for!(i, BYTES, {
+            data[BYTES - 1 - i] = self.bytes[i].reverse_bits();
+        });
+        Self::new(data)
+    }
+
+    /// shifts `self` left by `n` bits
+    /// returns the shifted set and whether any set bit was shifted out
+    pub fn overflowing_shl(self, n: usize) -> (Self, bool) {
+        let overflow = self.into_iter().rev().take(n).any(|bit| bit);
+        if n >= BYTES * 8 {
+            (Self::default(), overflow)
+        } else {
+            (self << n, overflow)
+        }
+    }
+    /// shifts `self` left by `K` bits, like `self << K`, but with the shift known at compile time
+    ///
+    /// shifting by all bits or more gives an empty set
+    pub const fn shl_const<const K: usize>(self) -> Self {
+        let mut bytes = [0; BYTES];
+        if K >= BYTES * 8 {
+            return Self::new(bytes);
+        }
+        let (s_bits, s_bytes) = Self::split_index(K);
+        const_for!(i, BYTES - s_bytes, {
+            bytes[i + s_bytes] = self.bytes[i] << s_bits;
+            if s_bits != 0 && i > 0 {
+                bytes[i + s_bytes] |= self.bytes[i - 1] >> (8 - s_bits);
+            }
+        });
+        Self::new(bytes)
+    }
+    /// shifts `self` right by `n` bits
+    /// returns the shifted set and whether any set bit was shifted out
+    pub fn overflowing_shr(self, n: usize) -> (Self, bool) {
+        let overflow = self.into_iter().take(n).any(|bit| bit);
+        if n >= BYTES * 8 {
+            (Self::default(), overflow)
+        } else {
+            (self >> n, overflow)
+        }
+    }
 }
 
 macro_rules! impl_ops {
@@ -278,6 +454,36 @@ impl<const BYTES: usize> std::ops::Shl<usize> for BitSet<BYTES> {
     }
 }
 
+impl<const BYTES: usize> std::ops::Shr<usize> for BitSet<BYTES> {
+    type Output = Self;
+
+    fn shr(mut self, rhs: usize) -> Self::Output {
+        match BYTES {
+            1 => self.bytes[0] >>= rhs,
+            _ if rhs == 0 => {}
+            _ => {
+                let (s_bits, s_bytes) = Self::split_index(rhs);
+                if s_bits == 0 {
+                    self.bytes.rotate_left(s_bytes);
+                    for i in BYTES - s_bytes..BYTES {
+                        self.bytes[i] = 0;
+                    }
+                } else {
+                    let old = std::mem::take(&mut self);
+                    old.into_iter()
+                        .skip(rhs)
+                        .enumerate()
+                        .filter(|&(_, bit)| bit)
+                        .for_each(|(i, _)| {
+                            self.set(i, true);
+                        });
+                }
+            }
+        }
+        self
+    }
+}
+
 impl<const BYTES: usize> From<BitSet<BYTES>> for [[bool; 8]; BYTES] {
     fn from(value: BitSet<BYTES>) -> Self {
         TryInto::<[_; BYTES]>::try_into(
@@ -341,6 +547,37 @@ impl<const BYTES: usize> IntoIterator for BitSet<BYTES> {
     }
 }
 
+/// (de)serializes the wrapped [`BitSet`] as the list of its set indices, like `[0, 3, 7]`
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BitSetIndices<const BYTES: usize>(pub BitSet<BYTES>);
+#[cfg(feature = "serde")]
+impl<const BYTES: usize> serde::Serialize for BitSetIndices<BYTES> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.0
+                .iter_indexed()
+                .filter_map(|(i, bit)| bit.then_some(i)),
+        )
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de, const BYTES: usize> serde::Deserialize<'de> for BitSetIndices<BYTES> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut set = BitSet::default();
+        for index in Vec::<usize>::deserialize(deserializer)? {
+            if index >= BYTES * 8 {
+                return Err(serde::de::Error::custom(format_args!(
+                    "index {index} out of bounds for {} bits",
+                    BYTES * 8
+                )));
+            }
+            set.set(index, true);
+        }
+        Ok(Self(set))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,6 +609,200 @@ mod tests {
         assert_eq!(BitSet::from(0b1010_0110_0000_0000), set);
     }
 
+    #[test]
+    fn slice() {
+        let set = BitSet::<2>::from_slice(&[0x0F, 0xF0]);
+        assert_eq!(
+            Some(BitSet::from(0xF00F)),
+            set,
+            "failed with matching length"
+        );
+        assert_eq!(vec![0x0F, 0xF0], set.unwrap().to_vec());
+
+        assert_eq!(
+            None,
+            BitSet::<2>::from_slice(&[0x0F]),
+            "failed with short slice"
+        );
+        assert_eq!(
+            None,
+            BitSet::<2>::from_slice(&[0x0F, 0xF0, 0x00]),
+            "failed with long slice"
+        );
+    }
+
+    #[test]
+    fn from_bools() {
+        let set = BitSet::<2>::from(0xA50Fu16);
+        let bools: [[bool; 8]; 2] = set.into();
+        assert_eq!(
+            set,
+            BitSet::from_bools(bools.into_iter().flatten()),
+            "failed round trip"
+        );
+        assert_eq!(
+            BitSet::<1>::from(0b0000_0101u8),
+            BitSet::from_bools([true, false, true]),
+            "failed with missing bits"
+        );
+        assert_eq!(
+            BitSet::<1>::all_set(),
+            BitSet::from_bools(std::iter::repeat(true)),
+            "failed with excess bits"
+        );
+    }
+
+    #[test]
+    fn concat() {
+        assert_eq!(
+            BitSet::<2>::from(0xCDABu16),
+            BitSet::concat(BitSet::<1>::from(0xABu8), BitSet::<1>::from(0xCDu8))
+        );
+        assert_eq!(
+            BitSet::<3>::from_slice(&[0x01, 0x02, 0x03]).unwrap(),
+            BitSet::concat(BitSet::<1>::from(0x01u8), BitSet::<2>::from(0x0302u16)),
+            "failed with differend sizes"
+        );
+    }
+
+    #[test]
+    fn try_from_u64() {
+        assert_eq!(
+            Ok(BitSet::<1>::from(0xFFu8)),
+            BitSet::try_from_u64(0xFF),
+            "failed fitting value"
+        );
+        assert!(
+            BitSet::<1>::try_from_u64(0x100).is_err(),
+            "accepted too wide value"
+        );
+        assert_eq!(
+            Ok(BitSet::<16>::from(u128::from(u64::MAX))),
+            BitSet::try_from_u64(u64::MAX),
+            "failed wider set"
+        );
+    }
+
+    #[test]
+    fn apply_masked() {
+        let mut set = BitSet::<1>::from(0b1010_1010u8);
+        set.apply_masked(&BitSet::from(0b0101_0101u8), &BitSet::from(0x0Fu8));
+        assert_eq!(
+            BitSet::from(0b1010_0101u8),
+            set,
+            "didn't update only the low nibble"
+        );
+    }
+
+    #[test]
+    fn reverse_bits() {
+        let set = BitSet::<2>::from(0x8001u16);
+        assert_eq!(set, set.reverse_bits(), "failed symmetric set");
+        let set = BitSet::<2>::from(0x0102u16);
+        assert_eq!(
+            BitSet::from(0x4080u16),
+            set.reverse_bits(),
+            "failed to mirror"
+        );
+        assert_eq!(
+            set,
+            set.reverse_bits().reverse_bits(),
+            "isn't an involution"
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let set = BitSet::<1>::from(0xABu8);
+        let grown = set.resize::<2>();
+        assert_eq!(BitSet::from(0x00ABu16), grown, "failed to grow");
+        assert_eq!(set, grown.resize::<1>(), "failed to shrink back");
+        assert_eq!(
+            BitSet::<1>::from(0x01u8),
+            BitSet::<2>::from(0xFF01u16).resize::<1>(),
+            "didn't drop high bytes"
+        );
+    }
+
+    #[test]
+    fn all() {
+        assert!(BitSet::<2>::all_set().all(), "all_set isn't all");
+        assert!(!BitSet::<2>::from(0xFFFE).all(), "missing bit was ignored");
+        assert!(!BitSet::<2>::default().all(), "empty set was all");
+
+        let mut set = BitSet::<3>::default();
+        set.set_all();
+        assert_eq!(3 * 8, set.count());
+        assert_eq!(BitSet::all_set(), set);
+    }
+
+    #[test]
+    fn sub() {
+        let set = BitSet::<4>::from(0x04_03_02_01u32);
+        assert_eq!(BitSet::<1>::from(0x02), set.sub(8), "failed byte aligned");
+        assert_eq!(BitSet::<1>::from(0x03), set.sub(16), "failed byte aligned");
+        assert_eq!(
+            BitSet::<2>::from(0x0302),
+            set.sub(8),
+            "failed multiple bytes"
+        );
+        assert_eq!(BitSet::<1>::from(0x20), set.sub(4), "failed unaligned");
+        assert_eq!(set, set.sub::<4>(0), "failed full copy");
+    }
+    #[test]
+    #[should_panic = "out of bounds"]
+    fn sub_out_of_bounds() {
+        let _ = BitSet::<4>::from(0x04_03_02_01u32).sub::<1>(25);
+    }
+
+    #[test]
+    fn indexed() {
+        let set = BitSet::<2>::from(0x00FF);
+        let mut iter = set.iter_indexed();
+        assert_eq!(16, iter.len());
+        assert_eq!(Some((15, false)), iter.next_back());
+        assert!(
+            iter.take(8).eq((0..8).map(|i| (i, true))),
+            "first eight bits weren't set"
+        );
+        assert!(
+            set.indexed().skip(8).all(|(i, bit)| i >= 8 && !bit),
+            "last eight bits were set"
+        );
+    }
+
+    #[test]
+    fn parity() {
+        let set = BitSet::<1>::from(0b1011u8);
+        assert!(set.parity(), "wrong parity for {set:?}");
+        assert!(!set.is_single_bit(), "{set:?} was single bit");
+
+        let set = BitSet::<1>::from(0b0100u8);
+        assert!(set.parity(), "wrong parity for {set:?}");
+        assert!(set.is_single_bit(), "{set:?} wasn't single bit");
+
+        let set = BitSet::<2>::from(0x0101u16);
+        assert!(!set.parity(), "wrong parity for {set:?}");
+        assert!(!set.is_single_bit(), "{set:?} was single bit");
+        assert!(
+            !BitSet::<2>::default().is_single_bit(),
+            "empty set was single bit"
+        );
+    }
+
+    #[test]
+    fn index_string() {
+        assert_eq!(
+            "{0, 1, 2, 3, 12, 13, 14, 15}",
+            BitSet::<2>::from(0xF00Fu16).to_index_string()
+        );
+        assert_eq!(
+            "{}",
+            BitSet::<2>::default().to_index_string(),
+            "failed empty"
+        );
+    }
+
     #[test]
     fn union() {
         assert_eq!(
@@ -392,6 +823,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn shift_const() {
+        let set = BitSet::<2>::from(0b1111_1111_1100_0001);
+        assert_eq!(set << 9, set.shl_const::<9>(), "failed with bits and bytes");
+        assert_eq!(set << 8, set.shl_const::<8>(), "failed with whole bytes");
+        assert_eq!(set << 3, set.shl_const::<3>(), "failed with bits");
+        assert_eq!(set, set.shl_const::<0>(), "failed without shift");
+        assert_eq!(
+            BitSet::default(),
+            set.shl_const::<16>(),
+            "failed with full shift"
+        );
+        assert_eq!(
+            BitSet::<4>::from(0x04_03_02_01u32) << 12,
+            BitSet::from(0x04_03_02_01u32).shl_const::<12>(),
+            "failed with more bytes"
+        );
+    }
+
+    #[test]
+    fn shift_right() {
+        assert_eq!(
+            BitSet::<4>::from(0x00_04_03_02u32),
+            BitSet::from(0x04_03_02_01u32) >> 8
+        );
+        assert_eq!(
+            BitSet::<2>::from(0b0000_0000_0111_1111),
+            BitSet::from(0b1111_1110_1100_0001) >> 9
+        );
+    }
+
+    #[test]
+    fn overflowing_shift() {
+        assert_eq!(
+            (BitSet::<2>::from(0x0200), true),
+            BitSet::from(0x8001u16).overflowing_shl(9)
+        );
+        assert_eq!(
+            (BitSet::<2>::from(0x0200), false),
+            BitSet::from(0x0001u16).overflowing_shl(9)
+        );
+        assert_eq!(
+            (BitSet::<2>::default(), true),
+            BitSet::from(0x0001u16).overflowing_shl(16)
+        );
+
+        assert_eq!(
+            (BitSet::<2>::from(0x0040), true),
+            BitSet::from(0x8001u16).overflowing_shr(9)
+        );
+        assert_eq!(
+            (BitSet::<2>::from(0x0040), false),
+            BitSet::from(0x8000u16).overflowing_shr(9)
+        );
+    }
+
     #[test]
     fn debug() {
         assert_eq!(
@@ -429,4 +916,24 @@ mod tests {
             format!("{:#b}", BitSet::from(0b0000_0101_1010_1111u16))
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_indices() {
+        let set = BitSet::<1>::from(0b1000_1001u8);
+        assert_eq!(
+            "[0,3,7]",
+            serde_json::to_string(&BitSetIndices(set)).unwrap(),
+            "failed to serialize"
+        );
+        assert_eq!(
+            BitSetIndices(set),
+            serde_json::from_str("[0, 3, 7]").unwrap(),
+            "failed to deserialize"
+        );
+        assert!(
+            serde_json::from_str::<BitSetIndices<1>>("[8]").is_err(),
+            "accepted out of bounds index"
+        );
+    }
 }
@@ -7,6 +7,10 @@ use std::borrow::Cow;
 pub trait Ext<'a> {
     /// returns a borrowed of the given Cow type
     fn reborrow(&'a self) -> Self;
+    /// converts into an owned Cow if `cond` is true, otherwise `self` is returned unchanged
+    fn into_owned_if(self, cond: bool) -> Self
+    where
+        Self: Sized;
 }
 
 impl<'a, 'c: 'a, B: ?Sized + 'a + ToOwned> Ext<'c> for Cow<'a, B> {
@@ -14,10 +18,46 @@ impl<'a, 'c: 'a, B: ?Sized + 'a + ToOwned> Ext<'c> for Cow<'a, B> {
     fn reborrow(&'c self) -> Self {
         Cow::Borrowed(self.as_ref())
     }
+    fn into_owned_if(self, cond: bool) -> Self {
+        if cond {
+            Cow::Owned(self.into_owned())
+        } else {
+            self
+        }
+    }
 }
 impl<'a, 'c: 'a, B: ?Sized + 'c + ToOwned> Ext<'c> for Option<Cow<'a, B>> {
     #[inline]
     fn reborrow(&'c self) -> Self {
         self.as_ref().map(Ext::reborrow)
     }
+    fn into_owned_if(self, cond: bool) -> Self {
+        self.map(|it| it.into_owned_if(cond))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_owned_if() {
+        let data = String::from("data");
+        let cow = Cow::Borrowed(data.as_str());
+        assert!(
+            matches!(cow.clone().into_owned_if(false), Cow::Borrowed("data")),
+            "converted without cond"
+        );
+        assert!(
+            matches!(cow.into_owned_if(true), Cow::Owned(it) if it == "data"),
+            "didn't convert with cond"
+        );
+        assert!(
+            matches!(
+                Some(Cow::Borrowed(data.as_str())).into_owned_if(true),
+                Some(Cow::Owned(_))
+            ),
+            "didn't convert option"
+        );
+    }
 }
@@ -3,6 +3,8 @@
 
 use std::time::Duration;
 
+use thiserror::Error;
+
 /// extention function for [Duration]
 pub trait Ext {
     /// returns the hours represented by this `self`
@@ -11,14 +13,26 @@ pub trait Ext {
     fn minutes(&self) -> u64;
     /// returns the seconds represented by this `self`
     fn seconds(&self) -> u64;
+    /// returns the total minutes represented by `self` including the fraction
+    fn as_secs_f_minutes(&self) -> f64;
+    /// returns the total hours represented by `self` including the fraction
+    fn total_hours_f64(&self) -> f64;
 
     /// retursn a displayable Version of Duration
     fn into_display(self) -> DurationDisplay;
     /// returns the absolute difference between `self` and `other`
     fn abs_diff(self, other: Duration) -> Duration;
+    /// returns `self - other` or [`Duration::ZERO`] when `other` is bigger
+    fn saturating_sub_dur(self, other: Duration) -> Duration;
+    /// returns `self + other` or `None` when it would overflow
+    fn checked_add_dur(self, other: Duration) -> Option<Duration>;
     /// returns if the distance between `self` and `other` is no less then `delta`
     #[allow(clippy::wrong_self_convention)]
     fn is_near_to(self, other: Duration, delta: Duration) -> bool;
+    /// returns the fraction of `whole` that `self` represents, which may be bigger than `1.0`
+    ///
+    /// for a zero `whole` this gives `1.0` if `self` is also zero and `0.0` otherwise
+    fn ratio(self, whole: Duration) -> f64;
 }
 
 impl Ext for Duration {
@@ -34,23 +48,42 @@ impl Ext for Duration {
     fn seconds(&self) -> u64 {
         self.as_secs() % 60
     }
+    #[inline]
+    fn as_secs_f_minutes(&self) -> f64 {
+        self.as_secs_f64() / 60.0
+    }
+    #[inline]
+    fn total_hours_f64(&self) -> f64 {
+        self.as_secs_f64() / 3600.0
+    }
     fn into_display(self) -> DurationDisplay {
         DurationDisplay(self)
     }
 
     #[inline]
     fn abs_diff(self, other: Duration) -> Duration {
-        if self < other {
-            other - self
-        } else {
-            self - other
-        }
+        Self::abs_diff(self, other)
+    }
+    #[inline]
+    fn saturating_sub_dur(self, other: Duration) -> Duration {
+        self.saturating_sub(other)
+    }
+    #[inline]
+    fn checked_add_dur(self, other: Duration) -> Option<Duration> {
+        self.checked_add(other)
     }
     #[inline]
     #[allow(unstable_name_collisions)]
     fn is_near_to(self, other: Duration, delta: Duration) -> bool {
         self.abs_diff(other) < delta
     }
+    #[inline]
+    fn ratio(self, whole: Duration) -> f64 {
+        if whole.is_zero() {
+            return if self.is_zero() { 1.0 } else { 0.0 };
+        }
+        self.as_secs_f64() / whole.as_secs_f64()
+    }
 }
 
 /// builds a [Duration] from the given data
@@ -65,18 +98,316 @@ pub const fn duration_from_h_m_s_m(
     Duration::new(hours * 3600 + minutes * 60 + seconds, millis * 1_000_000)
 }
 
+/// builds a [Duration] from fractional `secs`, rounded to the nearest nanosecond
+///
+/// negative and NaN values saturate to zero, too big values to [`Duration::MAX`]
+#[allow(clippy::module_name_repetitions)]
+pub fn duration_from_secs_f64(secs: f64) -> Duration {
+    if secs.is_nan() || secs <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+}
+/// builds a [Duration] from fractional `mins`, see [`duration_from_secs_f64`]
+#[allow(clippy::module_name_repetitions)]
+pub fn duration_from_minutes_f64(mins: f64) -> Duration {
+    duration_from_secs_f64(mins * 60.0)
+}
+/// builds a [Duration] from fractional `hours`, see [`duration_from_secs_f64`]
+#[allow(clippy::module_name_repetitions)]
+pub fn duration_from_hours_f64(hours: f64) -> Duration {
+    duration_from_secs_f64(hours * 3600.0)
+}
+
+/// returns the number of frames at `fps` in `duration`, rounded to the nearest frame
+///
+/// gives 0 when `fps` isn't positive
+pub fn to_frames(duration: Duration, fps: f64) -> u64 {
+    if fps.is_nan() || fps <= 0.0 {
+        return 0;
+    }
+    (duration.as_secs_f64() * fps).round() as u64
+}
+/// builds a [Duration] from `frames` at `fps`, see [`duration_from_secs_f64`]
+///
+/// gives [`Duration::ZERO`] when `fps` isn't positive
+pub fn from_frames(frames: u64, fps: f64) -> Duration {
+    if fps.is_nan() || fps <= 0.0 {
+        return Duration::ZERO;
+    }
+    duration_from_secs_f64(frames as f64 / fps)
+}
+
 /// a wrapper to hold a Duration for distplaing
+/// the Duration is rounded to the displayed millis
 // TODO add configurations
 #[allow(clippy::module_name_repetitions)]
 pub struct DurationDisplay(std::time::Duration);
 impl std::fmt::Display for DurationDisplay {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const NANOS_PER_MILLI: u128 = 1_000_000;
+        let millis = (self.0.as_nanos() + NANOS_PER_MILLI / 2) / NANOS_PER_MILLI;
         write!(
             f,
             "{}:{:0>2}.{:0>3}",
-            self.0.minutes(),
-            self.0.seconds(),
-            self.0.subsec_millis()
+            (millis / 60_000) % 60,
+            (millis / 1000) % 60,
+            millis % 1000
         )
     }
 }
+
+/// a wrapper to display and parse a Duration in the compact form `1h02m03s`
+///
+/// components that are zero are omitted when displaying, but accepted when parsing.
+/// Fractions of a second are truncated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompactDuration(pub Duration);
+impl std::fmt::Display for CompactDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut has_written = false;
+        for (value, unit) in [
+            (self.0.hours(), 'h'),
+            (self.0.minutes(), 'm'),
+            (self.0.seconds(), 's'),
+        ] {
+            if value == 0 {
+                continue;
+            }
+            if has_written {
+                write!(f, "{value:0>2}{unit}")?;
+            } else {
+                write!(f, "{value}{unit}")?;
+            }
+            has_written = true;
+        }
+        if !has_written {
+            write!(f, "0s")?;
+        }
+        Ok(())
+    }
+}
+/// an Error that can happen, when parsing a [`CompactDuration`]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseCompactError {
+    /// the input was empty
+    #[error("empty duration")]
+    Empty,
+    /// a number wasn't followed by a unit
+    #[error("missing unit after number")]
+    MissingUnit,
+    /// found a char that isn't a number or an expected unit, units need to be in the order h, m, s and may only be used once
+    #[error("unexpected char {0:?}")]
+    UnexpectedChar(char),
+    /// the duration is to big
+    #[error("duration overflowed")]
+    Overflow,
+}
+impl std::str::FromStr for CompactDuration {
+    type Err = ParseCompactError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const UNITS: [(char, u64); 3] = [('h', 3600), ('m', 60), ('s', 1)];
+        if s.is_empty() {
+            return Err(ParseCompactError::Empty);
+        }
+        let mut rest = s;
+        let mut units = UNITS.as_slice();
+        let mut secs = 0u64;
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or(ParseCompactError::MissingUnit)?;
+            let unit = rest[end..]
+                .chars()
+                .next()
+                .unwrap_or_else(|| unreachable!("end is at a char"));
+            if end == 0 {
+                return Err(ParseCompactError::UnexpectedChar(unit));
+            }
+            let value = rest[..end]
+                .parse::<u64>()
+                .map_err(|_| ParseCompactError::Overflow)?;
+            let index = units
+                .iter()
+                .position(|(it, _)| *it == unit)
+                .ok_or(ParseCompactError::UnexpectedChar(unit))?;
+            secs = value
+                .checked_mul(units[index].1)
+                .and_then(|value| secs.checked_add(value))
+                .ok_or(ParseCompactError::Overflow)?;
+            units = &units[index + 1..];
+            rest = &rest[end + unit.len_utf8()..];
+        }
+        Ok(Self(Duration::from_secs(secs)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fractional() {
+        assert!(
+            (Duration::from_secs(90).as_secs_f_minutes() - 1.5).abs() < f64::EPSILON,
+            "90 seconds aren't 1.5 minutes"
+        );
+        assert!(
+            (duration_from_h_m_s_m(1, 0, 0, 0).total_hours_f64() - 1.0).abs() < f64::EPSILON,
+            "1h isn't 1 hour"
+        );
+        assert!(
+            (duration_from_h_m_s_m(1, 30, 0, 0).total_hours_f64() - 1.5).abs() < f64::EPSILON,
+            "1h30m isn't 1.5 hours"
+        );
+    }
+
+    #[test]
+    fn ratio() {
+        let ratio =
+            |part: u64, whole: u64| Duration::from_secs(part).ratio(Duration::from_secs(whole));
+        assert!((ratio(30, 60) - 0.5).abs() < f64::EPSILON, "failed half");
+        assert!(
+            (ratio(90, 60) - 1.5).abs() < f64::EPSILON,
+            "failed over 100%"
+        );
+        assert!(ratio(30, 0).abs() < f64::EPSILON, "failed zero whole");
+        assert!((ratio(0, 0) - 1.0).abs() < f64::EPSILON, "failed both zero");
+    }
+
+    #[test]
+    fn frames() {
+        assert_eq!(
+            30,
+            to_frames(Duration::from_secs(1), 30.0),
+            "failed to frames"
+        );
+        assert_eq!(
+            Duration::from_secs(1),
+            from_frames(30, 30.0),
+            "failed from frames"
+        );
+        assert_eq!(
+            2,
+            to_frames(Duration::from_millis(50), 30.0),
+            "didn't round to nearest frame"
+        );
+        assert_eq!(0, to_frames(Duration::from_secs(1), 0.0), "failed zero fps");
+        assert_eq!(Duration::ZERO, from_frames(30, -1.0), "failed negative fps");
+    }
+
+    #[test]
+    fn from_fractional() {
+        assert_eq!(
+            Duration::from_secs(90),
+            duration_from_minutes_f64(1.5),
+            "1.5 minutes aren't 90 seconds"
+        );
+        assert_eq!(
+            Duration::from_mins(15),
+            duration_from_hours_f64(0.25),
+            "0.25 hours aren't 15 minutes"
+        );
+        assert_eq!(
+            Duration::ZERO,
+            duration_from_minutes_f64(-1.0),
+            "didn't clamp negative"
+        );
+        assert_eq!(
+            Duration::ZERO,
+            duration_from_hours_f64(f64::NAN),
+            "didn't clamp NaN"
+        );
+        assert_eq!(
+            Duration::MAX,
+            duration_from_hours_f64(f64::INFINITY),
+            "didn't saturate infinity"
+        );
+    }
+
+    #[test]
+    fn display() {
+        fn display(secs: u64, nanos: u32) -> String {
+            Duration::new(secs, nanos).into_display().to_string()
+        }
+        assert_eq!("1:01.234", display(61, 234_000_000));
+        assert_eq!("0:01.999", display(1, 999_400_000), "failed to round down");
+        assert_eq!(
+            "0:02.000",
+            display(1, 999_500_000),
+            "failed to carry into seconds"
+        );
+        assert_eq!(
+            "1:00.000",
+            display(59, 999_600_000),
+            "failed to carry into minutes"
+        );
+    }
+
+    #[test]
+    fn saturating_sub() {
+        assert_eq!(
+            Duration::from_secs(2),
+            Duration::from_secs(5).saturating_sub_dur(Duration::from_secs(3))
+        );
+        assert_eq!(
+            Duration::ZERO,
+            Duration::from_secs(3).saturating_sub_dur(Duration::from_secs(5)),
+            "failed to clamp to zero"
+        );
+    }
+
+    #[test]
+    fn checked_add() {
+        assert_eq!(
+            Some(Duration::from_secs(8)),
+            Duration::from_secs(5).checked_add_dur(Duration::from_secs(3))
+        );
+        assert_eq!(
+            None,
+            Duration::MAX.checked_add_dur(Duration::from_nanos(1)),
+            "failed to detect overflow"
+        );
+    }
+
+    #[test]
+    fn compact() {
+        fn parse(s: &str) -> Result<Duration, ParseCompactError> {
+            s.parse::<CompactDuration>().map(|it| it.0)
+        }
+        let full = duration_from_h_m_s_m(1, 2, 3, 0);
+        assert_eq!("1h02m03s", CompactDuration(full).to_string());
+        assert_eq!(Ok(full), parse("1h02m03s"), "failed full");
+        assert_eq!(
+            Ok(duration_from_h_m_s_m(1, 30, 0, 0)),
+            parse("90m"),
+            "failed overflowing minutes"
+        );
+        assert_eq!(
+            "1h30m",
+            CompactDuration(duration_from_h_m_s_m(0, 90, 0, 0)).to_string()
+        );
+        assert_eq!(
+            "2h05s",
+            CompactDuration(duration_from_h_m_s_m(2, 0, 5, 0)).to_string(),
+            "failed to omit zero"
+        );
+        assert_eq!("0s", CompactDuration(Duration::ZERO).to_string());
+        assert_eq!(
+            Ok(duration_from_h_m_s_m(2, 0, 5, 0)),
+            parse("2h00m05s"),
+            "failed with zero component"
+        );
+
+        assert_eq!(Err(ParseCompactError::Empty), parse(""));
+        assert_eq!(Err(ParseCompactError::MissingUnit), parse("1h02"));
+        assert_eq!(Err(ParseCompactError::UnexpectedChar('h')), parse("2m1h"));
+        assert_eq!(Err(ParseCompactError::UnexpectedChar('m')), parse("1m2m"));
+        assert_eq!(Err(ParseCompactError::UnexpectedChar(' ')), parse("1h 2m"));
+        assert_eq!(
+            Err(ParseCompactError::Overflow),
+            parse("99999999999999999999s")
+        );
+    }
+}
@@ -7,6 +7,8 @@ pub trait IteratorExt: Iterator + Sized {
     fn with_size(self, size: usize) -> ExactSizeWrapper<Self>;
     /// zips `other` to the left og `self`
     fn lzip<I: Iterator>(self, other: I) -> std::iter::Zip<I, Self>;
+    /// like [`Iterator::enumerate`], but counts from `start`
+    fn enumerate_from(self, start: usize) -> std::iter::Zip<std::ops::RangeFrom<usize>, Self>;
     /// checks if `self` is ordered
     #[allow(clippy::wrong_self_convention)]
     fn is_sorted(self) -> bool
@@ -15,6 +17,70 @@ pub trait IteratorExt: Iterator + Sized {
     /// checks if `self` is ordered by `ord`
     #[allow(clippy::wrong_self_convention)]
     fn is_sorted_by(self, ord: impl FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering) -> bool;
+    /// groups runs of adjacent elements with the same key into [`Vec`]s
+    fn group_consecutive_by_key<K, F>(self, key: F) -> GroupConsecutiveIterator<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K;
+    /// places the result of `sep` between each pair of adjacent elements
+    fn intersperse_with<F>(self, sep: F) -> IntersperseWithIterator<Self, F>
+    where
+        F: FnMut() -> Self::Item;
+    /// places a clone of `sep` between each pair of adjacent elements
+    fn intersperse(
+        self,
+        sep: Self::Item,
+    ) -> IntersperseWithIterator<Self, impl FnMut() -> Self::Item>
+    where
+        Self::Item: Clone;
+    /// returns the last `n` elements in a single pass, keeping at most `n` elements at a time
+    fn last_n(self, n: usize) -> Vec<Self::Item>;
+    /// sums all elements, but returns `None` as soon as the sum would overflow
+    fn sum_checked<S>(self) -> Option<S>
+    where
+        S: CheckedAdd,
+        Self::Item: Into<S>;
+    /// collects an iterator over triples into three [`Vec`]s in a single pass
+    fn unzip3<A, B, C>(self) -> (Vec<A>, Vec<B>, Vec<C>)
+    where
+        Self: Iterator<Item = (A, B, C)>;
+    /// yields the accumulator after applying `f` to each element, but not the `initial` one
+    fn running_reduce<ACC: Clone>(
+        self,
+        initial: ACC,
+        f: impl FnMut(&ACC, Self::Item) -> ACC,
+    ) -> impl Iterator<Item = ACC>;
+    /// like [`Iterator::find_map`], but stops early at the first `Err` returned by `f`
+    #[allow(clippy::missing_errors_doc)]
+    fn find_map_try<B, E>(
+        &mut self,
+        f: impl FnMut(Self::Item) -> Result<Option<B>, E>,
+    ) -> Result<Option<B>, E>;
+    /// collects all [`Ok`] and all [`Err`] values into seperate [`Vec`]s without stopping at the first error
+    fn partition_result<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Iterator<Item = Result<T, E>>;
+    /// extends `target` with all elements of `self` and returns it for chaining
+    fn collect_into<C: Extend<Self::Item>>(self, target: &mut C) -> &mut C;
+    /// like [`CloneIteratorExt::filter_surrounding`], but without needing [`Clone`],
+    /// the elements are moved through without being cloned
+    ///
+    /// `predicate` runs one element ahead, so it is already called for the next element before the current one is yielded.
+    /// The inner iterator is advanced up to two elements ahead, starting when this is created
+    fn filter_surrounding_moved<F>(self, predicate: F) -> SurroundingFilterIterator<Self, F>
+    where
+        F: FnMut(&Option<Self::Item>, &Self::Item, &Option<Self::Item>) -> bool;
+    /// like [`Iterator::scan`], but stops after the first `Err` was yielded
+    fn scan_try<ACC, R, E, F>(self, initial: ACC, f: F) -> ScanTryIterator<Self, ACC, F>
+    where
+        F: FnMut(&mut ACC, Self::Item) -> Result<R, E>;
+    /// like [`CloneIteratorExt::open_border_pairs`], but passes references to `f` without cloning
+    /// and stops at the first `Err` returned by `f`
+    #[allow(clippy::missing_errors_doc)]
+    fn for_each_border_pair_try<E>(
+        self,
+        f: impl FnMut(State<&Self::Item>) -> Result<(), E>,
+    ) -> Result<(), E>;
 }
 impl<Iter: Iterator> IteratorExt for Iter {
     fn with_size(self, size: usize) -> ExactSizeWrapper<Self> {
@@ -24,6 +90,10 @@ impl<Iter: Iterator> IteratorExt for Iter {
     fn lzip<I: Iterator>(self, other: I) -> std::iter::Zip<I, Self> {
         other.zip(self)
     }
+    #[inline]
+    fn enumerate_from(self, start: usize) -> std::iter::Zip<std::ops::RangeFrom<usize>, Self> {
+        self.lzip(start..)
+    }
     fn is_sorted(self) -> bool
     where
         Self::Item: Ord,
@@ -43,7 +113,151 @@ impl<Iter: Iterator> IteratorExt for Iter {
         }
         true
     }
+    fn group_consecutive_by_key<K, F>(self, key: F) -> GroupConsecutiveIterator<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupConsecutiveIterator::new(self, key)
+    }
+    fn intersperse_with<F>(self, sep: F) -> IntersperseWithIterator<Self, F>
+    where
+        F: FnMut() -> Self::Item,
+    {
+        IntersperseWithIterator::new(self, sep)
+    }
+    fn intersperse(
+        self,
+        sep: Self::Item,
+    ) -> IntersperseWithIterator<Self, impl FnMut() -> Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        IteratorExt::intersperse_with(self, move || sep.clone())
+    }
+    fn last_n(self, n: usize) -> Vec<Self::Item> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut buffer = std::collections::VecDeque::with_capacity(n.min(self.size_hint().0));
+        for item in self {
+            if buffer.len() == n {
+                buffer.pop_front();
+            }
+            buffer.push_back(item);
+        }
+        buffer.into()
+    }
+    fn sum_checked<S>(mut self) -> Option<S>
+    where
+        S: CheckedAdd,
+        Self::Item: Into<S>,
+    {
+        self.try_fold(S::ZERO, |sum, it| sum.checked_add(it.into()))
+    }
+    fn unzip3<A, B, C>(self) -> (Vec<A>, Vec<B>, Vec<C>)
+    where
+        Self: Iterator<Item = (A, B, C)>,
+    {
+        let (capacity, _) = self.size_hint();
+        let mut a = Vec::with_capacity(capacity);
+        let mut b = Vec::with_capacity(capacity);
+        let mut c = Vec::with_capacity(capacity);
+        for (e_a, e_b, e_c) in self {
+            a.push(e_a);
+            b.push(e_b);
+            c.push(e_c);
+        }
+        (a, b, c)
+    }
+    fn running_reduce<ACC: Clone>(
+        self,
+        initial: ACC,
+        mut f: impl FnMut(&ACC, Self::Item) -> ACC,
+    ) -> impl Iterator<Item = ACC> {
+        self.scan(initial, move |acc, it| {
+            *acc = f(acc, it);
+            Some(acc.clone())
+        })
+    }
+    fn find_map_try<B, E>(
+        &mut self,
+        mut f: impl FnMut(Self::Item) -> Result<Option<B>, E>,
+    ) -> Result<Option<B>, E> {
+        for item in self {
+            if let Some(found) = f(item)? {
+                return Ok(Some(found));
+            }
+        }
+        Ok(None)
+    }
+    fn partition_result<T, E>(self) -> (Vec<T>, Vec<E>)
+    where
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for item in self {
+            match item {
+                Ok(t) => oks.push(t),
+                Err(e) => errs.push(e),
+            }
+        }
+        (oks, errs)
+    }
+    fn collect_into<C: Extend<Self::Item>>(self, target: &mut C) -> &mut C {
+        target.extend(self);
+        target
+    }
+    fn filter_surrounding_moved<F>(self, predicate: F) -> SurroundingFilterIterator<Self, F>
+    where
+        F: FnMut(&Option<Self::Item>, &Self::Item, &Option<Self::Item>) -> bool,
+    {
+        SurroundingFilterIterator::new(self, predicate)
+    }
+    fn scan_try<ACC, R, E, F>(self, initial: ACC, f: F) -> ScanTryIterator<Self, ACC, F>
+    where
+        F: FnMut(&mut ACC, Self::Item) -> Result<R, E>,
+    {
+        ScanTryIterator::new(self, initial, f)
+    }
+    fn for_each_border_pair_try<E>(
+        self,
+        mut f: impl FnMut(State<&Self::Item>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut last = None;
+        for item in self {
+            match &last {
+                None => f(State::Start(&item))?,
+                Some(last) => f(State::Middle(last, &item))?,
+            }
+            last = Some(item);
+        }
+        last.map_or(Ok(()), |last| f(State::End(&last)))
+    }
+}
+/// numbers, that can be added without silently overflowing
+pub trait CheckedAdd: Sized {
+    /// the neutral element of the addition
+    const ZERO: Self;
+    /// adds `rhs` to `self` and returns `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
 }
+macro_rules! impl_checked_add {
+    ($($int:ty),*) => {
+        $(
+            impl CheckedAdd for $int {
+                const ZERO: Self = 0;
+                #[inline]
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$int>::checked_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 /// extentions for all Iterators over [futures](core::future::Future)
 #[cfg(feature = "fut_iter")]
 pub trait FutIterExt: IntoIterator + Sized
@@ -68,6 +282,8 @@ pub trait CloneIteratorExt: Iterator + Sized {
     /// cuts up the iterator in chunks of size `window_size`. The next Chunk starts `hop_lenght` after the last one started
     fn chunked(self, window_size: usize, hop_length: usize) -> ChunkedIterator<Self>;
     /// filters elements with respect to thier neighbors
+    ///
+    /// `predicate` runs one element ahead, see [`IteratorExt::filter_surrounding_moved`]
     fn filter_surrounding<F>(self, predicate: F) -> SurroundingFilterIterator<Self, F>
     where
         F: FnMut(&Option<Self::Item>, &Self::Item, &Option<Self::Item>) -> bool;
@@ -146,6 +362,59 @@ where
     }
 }
 
+/// like [`CloneIteratorExt::chunked`], but borrows the windows from `data` instead of allocating them
+///
+/// # Panics
+/// panics if `hop_length` is 0 or bigger than `window_size`
+pub fn slice_chunked<T>(
+    data: &[T],
+    window_size: usize,
+    hop_length: usize,
+) -> SliceChunkedIterator<'_, T> {
+    assert!(
+        0 < hop_length && hop_length <= window_size,
+        "hop_length needs to be in 1..=window_size"
+    );
+    SliceChunkedIterator {
+        data,
+        window_size,
+        hop_length,
+        start: 0,
+    }
+}
+#[allow(missing_docs)]
+pub struct SliceChunkedIterator<'a, T> {
+    data: &'a [T],
+    window_size: usize,
+    hop_length: usize,
+    start: usize,
+}
+impl<'a, T> Iterator for SliceChunkedIterator<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.data.len() {
+            return None;
+        }
+        let end = (self.start + self.window_size).min(self.data.len());
+        let window = &self.data[self.start..end];
+        self.start += self.hop_length;
+        Some(window)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+impl<T> ExactSizeIterator for SliceChunkedIterator<'_, T> {
+    fn len(&self) -> usize {
+        self.data
+            .len()
+            .saturating_sub(self.start)
+            .div_ceil(self.hop_length)
+    }
+}
+
 #[allow(missing_docs)]
 pub struct SurroundingFilterIterator<
     Iter: Iterator,
@@ -153,20 +422,20 @@ pub struct SurroundingFilterIterator<
 > {
     iter: Iter,
     predicate: F,
-    last: Option<Iter::Item>,
+    /// if `element` should be included, is only `None` before the first element is checked
+    include: Option<bool>,
     element: Option<Iter::Item>,
     next: Option<Iter::Item>,
 }
 impl<Iter, F> SurroundingFilterIterator<Iter, F>
 where
     Iter: Iterator,
-    Iter::Item: Clone,
     F: FnMut(&Option<Iter::Item>, &Iter::Item, &Option<Iter::Item>) -> bool,
 {
     fn new(mut iter: Iter, predicate: F) -> Self {
         Self {
             predicate,
-            last: None,
+            include: None,
             element: iter.next(),
             next: iter.next(),
             iter,
@@ -176,20 +445,146 @@ where
 impl<Iter, F> Iterator for SurroundingFilterIterator<Iter, F>
 where
     Iter: Iterator,
-    Iter::Item: Clone,
     F: FnMut(&Option<Iter::Item>, &Iter::Item, &Option<Iter::Item>) -> bool,
 {
     type Item = Iter::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let include = (self.predicate)(&self.last, self.element.as_ref()?, &self.next);
-        let element = std::mem::replace(&mut self.next, self.iter.next()); // get next element
-        self.last = std::mem::replace(&mut self.element, element);
-        if include {
-            Some(self.last.clone().unwrap()) // return clone of self.last==old.element
-        } else {
-            self.next() // skip this element
+        loop {
+            let include = match self.include.take() {
+                Some(include) => include,
+                None => (self.predicate)(&None, self.element.as_ref()?, &self.next),
+            };
+            // advance and check the new element while the old one is still owned, so it can be moved out afterwards
+            let last = std::mem::replace(&mut self.element, self.next.take());
+            self.next = self.iter.next();
+            if let Some(element) = self.element.as_ref() {
+                self.include = Some((self.predicate)(&last, element, &self.next));
+            }
+            if include {
+                return last;
+            }
+        }
+    }
+}
+
+#[allow(missing_docs)]
+pub struct ScanTryIterator<Iter, ACC, F> {
+    iter: Iter,
+    state: ACC,
+    f: F,
+    failed: bool,
+}
+impl<Iter, ACC, F> ScanTryIterator<Iter, ACC, F> {
+    const fn new(iter: Iter, state: ACC, f: F) -> Self {
+        Self {
+            iter,
+            state,
+            f,
+            failed: false,
+        }
+    }
+}
+impl<Iter, ACC, R, E, F> Iterator for ScanTryIterator<Iter, ACC, F>
+where
+    Iter: Iterator,
+    F: FnMut(&mut ACC, Iter::Item) -> Result<R, E>,
+{
+    type Item = Result<R, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let res = (self.f)(&mut self.state, self.iter.next()?);
+        self.failed = res.is_err();
+        Some(res)
+    }
+}
+
+#[allow(missing_docs)]
+pub struct GroupConsecutiveIterator<Iter: Iterator, K, F> {
+    iter: Iter,
+    key: F,
+    next: Option<(K, Iter::Item)>,
+}
+impl<Iter, K, F> GroupConsecutiveIterator<Iter, K, F>
+where
+    Iter: Iterator,
+    K: PartialEq,
+    F: FnMut(&Iter::Item) -> K,
+{
+    const fn new(iter: Iter, key: F) -> Self {
+        Self {
+            iter,
+            key,
+            next: None,
+        }
+    }
+}
+impl<Iter, K, F> Iterator for GroupConsecutiveIterator<Iter, K, F>
+where
+    Iter: Iterator,
+    K: PartialEq,
+    F: FnMut(&Iter::Item) -> K,
+{
+    type Item = Vec<Iter::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (group_key, first) = match self.next.take() {
+            Some(next) => next,
+            None => {
+                let first = self.iter.next()?;
+                ((self.key)(&first), first)
+            }
+        };
+        let mut group = vec![first];
+        for item in self.iter.by_ref() {
+            let key = (self.key)(&item);
+            if key != group_key {
+                self.next = Some((key, item));
+                break;
+            }
+            group.push(item);
+        }
+        Some(group)
+    }
+}
+
+#[allow(missing_docs)]
+pub struct IntersperseWithIterator<Iter: Iterator, F> {
+    iter: std::iter::Peekable<Iter>,
+    sep: F,
+    needs_sep: bool,
+}
+impl<Iter, F> IntersperseWithIterator<Iter, F>
+where
+    Iter: Iterator,
+    F: FnMut() -> Iter::Item,
+{
+    fn new(iter: Iter, sep: F) -> Self {
+        Self {
+            iter: iter.peekable(),
+            sep,
+            needs_sep: false,
+        }
+    }
+}
+impl<Iter, F> Iterator for IntersperseWithIterator<Iter, F>
+where
+    Iter: Iterator,
+    F: FnMut() -> Iter::Item,
+{
+    type Item = Iter::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.needs_sep && self.iter.peek().is_some() {
+            self.needs_sep = false;
+            return Some((self.sep)());
         }
+        let item = self.iter.next()?;
+        self.needs_sep = true;
+        Some(item)
     }
 }
 
@@ -305,6 +700,29 @@ mod tests {
         assert!(&is.eq(&expected), "expected {expected:?} but was {is:?}");
     }
 
+    #[test]
+    fn slice_chunked_test() {
+        let data = (0..15).collect_vec();
+        for (window_size, hop_length) in [(6, 4), (6, 2), (4, 4), (1, 1), (20, 3)] {
+            let is = slice_chunked(&data, window_size, hop_length);
+            let expected = data
+                .iter()
+                .copied()
+                .chunked(window_size, hop_length)
+                .collect_vec();
+            assert_eq!(
+                expected.len(),
+                is.len(),
+                "wrong len for {window_size}, {hop_length}"
+            );
+            assert!(
+                is.eq(expected.iter().map(Vec::as_slice)),
+                "differend windows for {window_size}, {hop_length}"
+            );
+        }
+        assert_eq!(0, slice_chunked::<u8>(&[], 2, 1).count(), "failed empty");
+    }
+
     #[test]
     fn surrounding_filter_test() {
         let is = (0..4)
@@ -316,6 +734,42 @@ mod tests {
         assert!(&is.eq(&expected), "expected {expected:?} but got {is:?}");
     }
     #[test]
+    fn surrounding_filter_look_ahead() {
+        use std::cell::RefCell;
+        let pulled = RefCell::new(Vec::new());
+        let checked = RefCell::new(Vec::new());
+        let mut iter = (0..4)
+            .inspect(|&it| pulled.borrow_mut().push(it))
+            .filter_surrounding_moved(|_, &e, _| {
+                checked.borrow_mut().push(e);
+                true
+            });
+        assert_eq!(vec![0, 1], *pulled.borrow(), "didn't pull two on creation");
+        assert_eq!(Some(0), iter.next());
+        assert_eq!(vec![0, 1], *checked.borrow(), "didn't check one ahead");
+        assert_eq!(vec![0, 1, 2], *pulled.borrow(), "didn't pull two ahead");
+    }
+    #[test]
+    fn surrounding_filter_no_clone() {
+        use std::{cell::Cell, rc::Rc};
+        #[derive(Debug)]
+        struct CloneCounter(usize, Rc<Cell<usize>>);
+        impl Clone for CloneCounter {
+            fn clone(&self) -> Self {
+                self.1.set(self.1.get() + 1);
+                Self(self.0, self.1.clone())
+            }
+        }
+        let clones = Rc::new(Cell::new(0));
+        let is = (0..4)
+            .map(|it| CloneCounter(it, clones.clone()))
+            .filter_surrounding_moved(|_, e, _| e.0 != 1)
+            .map(|it| it.0)
+            .collect_vec();
+        assert_eq!(vec![0, 2, 3], is);
+        assert_eq!(0, clones.get(), "elements were cloned");
+    }
+    #[test]
     fn open_border_iter() {
         let iter = [1, 2, 3].into_iter().open_border_pairs();
         assert_eq!(iter.len(), 4);
@@ -328,6 +782,203 @@ mod tests {
         .into_iter()));
     }
 
+    #[test]
+    fn for_each_border_pair_try() {
+        let mut seen = Vec::new();
+        let is = [1, 2, 3, 4].into_iter().for_each_border_pair_try(|state| {
+            let state = match state {
+                State::Start(a) => State::Start(*a),
+                State::Middle(a, b) => State::Middle(*a, *b),
+                State::End(a) => State::End(*a),
+            };
+            let stop = state == State::Middle(2, 3);
+            seen.push(state);
+            if stop {
+                Err("stopped")
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(Err("stopped"), is, "didn't return error");
+        assert_eq!(
+            vec![State::Start(1), State::Middle(1, 2), State::Middle(2, 3)],
+            seen,
+            "didn't stop at error"
+        );
+
+        let mut seen = Vec::new();
+        let is = std::iter::once(1).for_each_border_pair_try(|state| {
+            seen.push(matches!(state, State::Start(1) | State::End(1)));
+            Ok::<_, ()>(())
+        });
+        assert_eq!(Ok(()), is, "failed single");
+        assert_eq!(vec![true, true], seen, "wrong states for single");
+        let is = std::iter::empty::<u8>().for_each_border_pair_try(|_| Err(()));
+        assert_eq!(Ok(()), is, "called for empty");
+    }
+
+    #[test]
+    fn group_consecutive() {
+        let groups = [1, 1, 2, 3, 3, 3]
+            .into_iter()
+            .group_consecutive_by_key(|&it| it)
+            .collect_vec();
+        assert_eq!(vec![vec![1, 1], vec![2], vec![3, 3, 3]], groups);
+
+        assert_eq!(
+            0,
+            std::iter::empty::<u8>()
+                .group_consecutive_by_key(|&it| it)
+                .count(),
+            "empty iterator shouldn't yield a group"
+        );
+    }
+
+    #[test]
+    fn intersperse() {
+        assert_eq!(
+            Vec::<u8>::new(),
+            IteratorExt::intersperse(std::iter::empty(), 0).collect_vec(),
+            "failed with empty iterator"
+        );
+        assert_eq!(
+            vec![1],
+            IteratorExt::intersperse(std::iter::once(1), 0).collect_vec(),
+            "failed with single element"
+        );
+        assert_eq!(
+            vec![1, 0, 2, 0, 3],
+            IteratorExt::intersperse(1..=3, 0).collect_vec(),
+            "failed with multiple elements"
+        );
+
+        let mut count = 0;
+        assert_eq!(
+            vec![1, -1, 2, -2, 3],
+            IteratorExt::intersperse_with(1..=3, || {
+                count -= 1;
+                count
+            })
+            .collect_vec(),
+            "failed with separator function"
+        );
+    }
+
+    #[test]
+    fn enumerate_from() {
+        assert_eq!(
+            vec![(1, 'a'), (2, 'b'), (3, 'c')],
+            ['a', 'b', 'c'].into_iter().enumerate_from(1).collect_vec()
+        );
+    }
+
+    #[test]
+    fn last_n() {
+        assert_eq!(vec![1, 2, 3], (1..=3).last_n(5), "failed with larger n");
+        assert_eq!(vec![1, 2, 3], (1..=3).last_n(3), "failed with equal n");
+        assert_eq!(vec![2, 3], (1..=3).last_n(2), "failed with smaller n");
+        assert!((1..=3).last_n(0).is_empty(), "failed with zero");
+    }
+
+    #[test]
+    fn sum_checked() {
+        assert_eq!(Some(10), (1..5).sum_checked::<u8>(), "failed normal sum");
+        assert_eq!(
+            None,
+            [200u8, 100].into_iter().sum_checked::<u8>(),
+            "didn't detect overflow"
+        );
+        assert_eq!(
+            Some(300),
+            [200u8, 100].into_iter().sum_checked::<u16>(),
+            "failed to widen"
+        );
+        assert_eq!(
+            Some(0),
+            std::iter::empty::<i32>().sum_checked::<i32>(),
+            "failed empty"
+        );
+        assert_eq!(
+            None,
+            [i8::MIN, -1].into_iter().sum_checked::<i8>(),
+            "didn't detect underflow"
+        );
+    }
+
+    #[test]
+    fn unzip3() {
+        let (a, b, c) = [(1, 'a', true), (2, 'b', false)].into_iter().unzip3();
+        assert_eq!(vec![1, 2], a, "failed first");
+        assert_eq!(vec!['a', 'b'], b, "failed second");
+        assert_eq!(vec![true, false], c, "failed third");
+    }
+
+    #[test]
+    fn running_reduce() {
+        assert_eq!(
+            vec![1, 3, 6],
+            [1, 2, 3]
+                .into_iter()
+                .running_reduce(0, |a, x| a + x)
+                .collect_vec(),
+            "failed running sum"
+        );
+        assert_eq!(
+            0,
+            std::iter::empty::<u8>()
+                .running_reduce(0, |a, x| a + x)
+                .count(),
+            "yielded initial"
+        );
+    }
+
+    #[test]
+    fn find_map_try() {
+        let parse = |s: &str| s.parse::<i32>().map(|it| (it > 2).then_some(it));
+        let mut iter = ["1", "3", "x", "4"].into_iter();
+        assert_eq!(Ok(Some(3)), iter.find_map_try(parse), "failed found");
+        assert!(iter.find_map_try(parse).is_err(), "didn't stop at error");
+        assert_eq!(Some("4"), iter.next(), "consumed after error");
+        assert_eq!(
+            Ok(None),
+            ["1", "2"].into_iter().find_map_try(parse),
+            "failed not found"
+        );
+    }
+
+    #[test]
+    fn partition_result() {
+        let (oks, errs) =
+            IteratorExt::partition_result([Ok(1), Err('a'), Ok(2), Err('b'), Ok(3)].into_iter());
+        assert_eq!(vec![1, 2, 3], oks, "failed oks");
+        assert_eq!(vec!['a', 'b'], errs, "failed errs");
+    }
+
+    #[test]
+    fn collect_into() {
+        let mut buffer = vec![1, 2];
+        IteratorExt::collect_into(3..5, &mut buffer).push(5);
+        assert_eq!(vec![1, 2, 3, 4, 5], buffer, "didn't keep prior elements");
+    }
+
+    #[test]
+    fn scan_try() {
+        let mut called = 0;
+        let is = (1..10)
+            .scan_try(0, |sum, it| {
+                called += 1;
+                *sum += it;
+                if *sum > 5 {
+                    Err(*sum)
+                } else {
+                    Ok(*sum)
+                }
+            })
+            .collect_vec();
+        assert_eq!(vec![Ok(1), Ok(3), Err(6)], is);
+        assert_eq!(3, called, "continued after error");
+    }
+
     #[test]
     fn exact_size() {
         let mut iter = (0..10).with_size(10);
@@ -11,6 +11,16 @@ pub trait Ext {
     /// Returns `true` if the option is a [`None`] or the value inside of it matches a predicate.
     #[allow(clippy::wrong_self_convention)]
     fn is_none_or(self, f: impl FnOnce(Self::Type) -> bool) -> bool;
+    /// calls `f` with the contained value if it is [`Some`] and returns `self` unchanged
+    fn tap_some(self, f: impl FnOnce(&Self::Type)) -> Self;
+    /// calls `f` if `self` is [`None`] and returns `self` unchanged
+    fn tap_none(self, f: impl FnOnce()) -> Self;
+    /// returns the hold value or inserts the result of `f`, which only gets `key` when it is called
+    fn get_or_insert_with_key<K>(
+        &mut self,
+        key: K,
+        f: impl FnOnce(K) -> Self::Type,
+    ) -> &mut Self::Type;
 }
 
 impl<T> Ext for Option<T> {
@@ -23,14 +33,77 @@ impl<T> Ext for Option<T> {
             Some(x) => f(x),
         }
     }
+    #[inline]
+    fn tap_some(self, f: impl FnOnce(&T)) -> Self {
+        if let Some(x) = &self {
+            f(x);
+        }
+        self
+    }
+    #[inline]
+    fn tap_none(self, f: impl FnOnce()) -> Self {
+        if self.is_none() {
+            f();
+        }
+        self
+    }
+    #[inline]
+    fn get_or_insert_with_key<K>(&mut self, key: K, f: impl FnOnce(K) -> T) -> &mut T {
+        self.get_or_insert_with(|| f(key))
+    }
 }
 
 #[test]
+#[allow(clippy::nonminimal_bool)]
 fn is_none_or() {
     assert!(Some(2).is_none_or(|x| x > 1));
     assert!(!Some(0).is_none_or(|x| x > 1));
     assert!(None::<usize>.is_none_or(|x| x > 1));
 }
+#[test]
+fn tap() {
+    let mut seen = None;
+    assert_eq!(
+        Some(2),
+        Some(2).tap_some(|x| seen = Some(*x)),
+        "changed some"
+    );
+    assert_eq!(Some(2), seen, "didn't tap some");
+    assert_eq!(Some(2), Some(2).tap_none(|| seen = None), "changed some");
+    assert_eq!(Some(2), seen, "tapped none for some");
+
+    assert_eq!(
+        None,
+        None::<u8>.tap_some(|_| seen = Some(3)),
+        "changed none"
+    );
+    assert_eq!(Some(2), seen, "tapped some for none");
+    assert_eq!(None, None::<u8>.tap_none(|| seen = None), "changed none");
+    assert_eq!(None, seen, "didn't tap none");
+}
+#[test]
+fn get_or_insert_with_key() {
+    let mut calls = 0;
+    let mut cache = None;
+    assert_eq!(
+        4,
+        *cache.get_or_insert_with_key(String::from("miss"), |key| {
+            calls += 1;
+            key.len()
+        }),
+        "wrong value on miss"
+    );
+    assert_eq!(1, calls, "didn't call once on miss");
+    assert_eq!(
+        4,
+        *cache.get_or_insert_with_key(String::from("hit"), |key| {
+            calls += 1;
+            key.len()
+        }),
+        "replaced value on hit"
+    );
+    assert_eq!(1, calls, "called on hit");
+}
 
 /// extentions for Options<Future<_>>
 #[async_trait::async_trait]
@@ -77,3 +150,44 @@ impl<T: Send> FutureExt for Option<T> {
         }
     }
 }
+
+/// extentions for Options<Future<Option<_>>>
+#[async_trait::async_trait]
+pub trait FlattenFutureExt {
+    /// The type of value produced by the inner option.
+    type Type;
+    /// awaits the held future if present, giving `None` when `self` is [`None`]
+    async fn resolve(self) -> Option<Self::Type>;
+}
+#[async_trait::async_trait]
+impl<T, F> FlattenFutureExt for Option<F>
+where
+    F: Future<Output = Option<T>> + Send,
+{
+    type Type = T;
+    async fn resolve(self) -> Option<T> {
+        match self {
+            Some(f) => f.await,
+            None => None,
+        }
+    }
+}
+#[cfg(feature = "io")]
+#[tokio::test]
+async fn resolve() {
+    assert_eq!(
+        Some(1),
+        Some(async { Some(1) }).resolve().await,
+        "failed inner some"
+    );
+    assert_eq!(
+        None,
+        Some(async { None::<u8> }).resolve().await,
+        "failed inner none"
+    );
+    assert_eq!(
+        None,
+        None::<std::future::Ready<Option<u8>>>.resolve().await,
+        "failed outer none"
+    );
+}
@@ -41,6 +41,44 @@ impl<T> FindOrPush<T> for Vec<T> {
         &mut self[index]
     }
 }
+/// extentions to find an Element in a sorted collection or insert it at its sorted position
+pub trait SearchOrInsert<Type> {
+    /// binary searches for an element with `key` or inserts the result of `make` where it keeps `self` sorted
+    ///
+    /// `self` needs to be sorted by `get_key`
+    fn search_or_insert_by_key<K: Ord>(
+        &mut self,
+        key: K,
+        make: impl FnOnce() -> Type,
+        get_key: impl Fn(&Type) -> K,
+    ) -> &mut Type;
+}
+impl<T> SearchOrInsert<T> for Vec<T> {
+    fn search_or_insert_by_key<K: Ord>(
+        &mut self,
+        key: K,
+        make: impl FnOnce() -> T,
+        get_key: impl Fn(&T) -> K,
+    ) -> &mut T {
+        let index = self
+            .binary_search_by_key(&key, get_key)
+            .unwrap_or_else(|index| {
+                self.insert(index, make());
+                index
+            });
+        &mut self[index]
+    }
+}
+/// extentions to remove all Elements matching a condition
+pub trait RemoveWhere<Type> {
+    /// removes all elements matching `predicate` and returns them, keeping the order of both
+    fn remove_where(&mut self, predicate: impl FnMut(&Type) -> bool) -> Vec<Type>;
+}
+impl<T> RemoveWhere<T> for Vec<T> {
+    fn remove_where(&mut self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        self.extract_if(.., |t| predicate(t)).collect()
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -64,4 +102,29 @@ mod tests {
         *element = 8;
         assert!(data.iter().eq(&[1, 2, 4, 8]), "first element got changed");
     }
+
+    #[test]
+    fn remove_where() {
+        let mut data = vec![1, 2, 3, 4];
+        let removed = data.remove_where(|it| it % 2 == 0);
+        assert_eq!(vec![2, 4], removed, "wrong removed elements");
+        assert_eq!(vec![1, 3], data, "wrong kept elements");
+    }
+
+    #[test]
+    fn search_or_insert() {
+        let mut data = vec![(1, 'a'), (3, 'c'), (5, 'e')];
+        let element = data.search_or_insert_by_key(3, || (3, 'x'), |it| it.0);
+        assert_eq!((3, 'c'), *element, "didn't find existing");
+        element.1 = 'C';
+        assert_eq!(3, data.len(), "inserted existing");
+
+        let element = data.search_or_insert_by_key(4, || (4, 'd'), |it| it.0);
+        assert_eq!((4, 'd'), *element, "didn't insert missing");
+        assert_eq!(
+            vec![(1, 'a'), (3, 'C'), (4, 'd'), (5, 'e')],
+            data,
+            "didn't keep the order"
+        );
+    }
 }
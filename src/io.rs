@@ -3,7 +3,7 @@
 
 #![cfg(feature = "io")]
 //! A module for io related Utilitys
-use log::{debug, trace};
+use log::{debug, trace, warn};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tokio::io::AsyncReadExt;
@@ -39,9 +39,18 @@ impl From<IoError> for MoveError {
     }
 }
 
+/// options for [`move_file_with`] and [`move_files_with`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MoveOptions {
+    /// simulates the move and prints a message, the checks for `file` and `dst` are still run
+    pub dry_run: bool,
+    /// creates `dst` and its parents when missing, a dry run only accepts a missing `dst`
+    pub create_dst: bool,
+}
+
 /// moves `file` to `dst`
 /// trys to rename the file, but copys an deletes old, when on differend devices
-/// `dry_run` simulates the move and prints a message
+/// `dry_run` simulates the move and prints a message, the checks for `file` and `dst` are still run
 ///
 /// # Errors
 /// - [`MoveError::FileNotFound`] when `file` doesn't exist
@@ -52,29 +61,94 @@ pub async fn move_file<P1: AsRef<Path> + Send + Sync, P2: AsRef<Path> + Send + S
     dst: P2,
     dry_run: bool,
 ) -> Result<(), (MoveError, P1, P2)> {
-    inner_move_file(file.as_ref(), dst.as_ref(), dry_run)
+    let options = MoveOptions {
+        dry_run,
+        ..Default::default()
+    };
+    move_file_with(file, dst, options).await
+}
+/// moves `file` to `dst` like [`move_file`], but with all [`MoveOptions`]
+///
+/// # Errors
+/// - [`MoveError::FileNotFound`] when `file` doesn't exist
+/// - [`MoveError::TargetNotFound`] when `dst` doesn't exist and [`MoveOptions::create_dst`] is false
+/// - [`MoveError::OtherIO`] will relay any other error
+pub async fn move_file_with<P1: AsRef<Path> + Send + Sync, P2: AsRef<Path> + Send + Sync>(
+    file: P1,
+    dst: P2,
+    options: MoveOptions,
+) -> Result<(), (MoveError, P1, P2)> {
+    inner_move_file(file.as_ref(), dst.as_ref(), options)
         .await
         .map_err(|err| (err, file, dst))
 }
-async fn inner_move_file(file: &Path, dst: &Path, dry_run: bool) -> Result<(), MoveError> {
-    if !tokio::fs::try_exists(dst).await? && tokio::fs::metadata(dst).await?.is_dir() {
+/// moves all files to thier targets like [`move_file`], without stopping at the first error
+///
+/// returns for each pair either the new path of the file or the error together with the pair
+pub async fn move_files(
+    pairs: impl IntoIterator<Item = (PathBuf, PathBuf)> + Send,
+    dry_run: bool,
+) -> Vec<Result<PathBuf, (MoveError, PathBuf, PathBuf)>> {
+    let options = MoveOptions {
+        dry_run,
+        ..Default::default()
+    };
+    move_files_with(pairs, options).await
+}
+/// moves all files to thier targets like [`move_files`], but with all [`MoveOptions`]
+pub async fn move_files_with(
+    pairs: impl IntoIterator<Item = (PathBuf, PathBuf)> + Send,
+    options: MoveOptions,
+) -> Vec<Result<PathBuf, (MoveError, PathBuf, PathBuf)>> {
+    let pairs = pairs.into_iter().collect::<Vec<_>>();
+    let mut results = Vec::with_capacity(pairs.len());
+    for (file, dst) in pairs {
+        // a missing file name will fail the move anyway
+        let target = dst.join(file.file_name().unwrap_or_default());
+        results.push(move_file_with(file, dst, options).await.map(|()| target));
+    }
+    results
+}
+async fn inner_move_file(file: &Path, dst: &Path, options: MoveOptions) -> Result<(), MoveError> {
+    let MoveOptions {
+        dry_run,
+        create_dst,
+    } = options;
+    let is_file = match tokio::fs::metadata(file).await {
+        Ok(it) => it.is_file(),
+        Err(err) if err.kind() == ErrorKind::NotFound => false,
+        Err(err) => return Err(MoveError::OtherIO(err)),
+    };
+    if create_dst && is_file && !dry_run {
+        tokio::fs::create_dir_all(dst).await?;
+    }
+    let is_dir = match tokio::fs::metadata(dst).await {
+        Ok(it) => it.is_dir(),
+        // only missing when the creation was skipped for a dry run or a missing file
+        Err(err) if err.kind() == ErrorKind::NotFound => create_dst,
+        Err(err) => return Err(MoveError::OtherIO(err)),
+    };
+    if !is_dir {
         return Err(MoveError::TargetNotFound);
     }
-    if !tokio::fs::try_exists(file).await? && tokio::fs::metadata(dst).await?.is_file() {
+    if !is_file {
         return Err(MoveError::FileNotFound);
     }
     if dry_run {
-        println!("moving {file:?} to {dst:?}");
+        println!("moving {} to {}", file.display(), dst.display());
         return Ok(());
     }
 
     let mut dst = dst.to_path_buf();
     dst.push(file.file_name().unwrap());
-    trace!("moving {file:?} to {dst:?}");
+    trace!("moving {} to {}", file.display(), dst.display());
     match tokio::fs::rename(&file, &dst).await {
         Ok(()) => Ok(()),
         Err(_err) /* TODO if err.kind() == IoErrorKind::CrossesDevices is unstable*/ => {
             debug!("couldn't just rename file, try to copy and remove old");
+            #[cfg(feature = "hash")]
+            copy_verified(&file, &dst).await?;
+            #[cfg(not(feature = "hash"))]
             tokio::fs::copy(&file, &dst).await?;
             tokio::fs::remove_file(&file).await?;
             Ok(())
@@ -83,6 +157,87 @@ async fn inner_move_file(file: &Path, dst: &Path, dry_run: bool) -> Result<(), M
     }
 }
 
+/// creates all missing parent directories of `path`
+///
+/// # Errors
+/// relays any Errors from [creating the directories](tokio::fs::create_dir_all)
+pub async fn ensure_parent_dirs(path: impl AsRef<Path> + Send) -> std::io::Result<()> {
+    match path.as_ref().parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => tokio::fs::create_dir_all(parent).await,
+        _ => Ok(()),
+    }
+}
+
+/// The supported algorithms for [`hash_file`]
+#[cfg(feature = "hash")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    /// SHA-256 with a 32 byte digest
+    Sha256,
+}
+
+/// calculates the digest of the file at `path` with `algo`
+/// the file is read in chunks, so it never needs to be fully in memory
+///
+/// # Errors
+/// relays any Errors from io calls
+#[cfg(feature = "hash")]
+pub async fn hash_file(
+    path: impl AsRef<Path> + Send,
+    algo: HashAlgorithm,
+) -> std::io::Result<Vec<u8>> {
+    use sha2::Digest;
+
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = match algo {
+        HashAlgorithm::Sha256 => sha2::Sha256::new(),
+    };
+    let mut buf = vec![0u8; 8 * 1024];
+    loop {
+        match file.read(&mut buf).await? {
+            0 => break,
+            bytes_read => hasher.update(&buf[..bytes_read]),
+        }
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+/// copies `src` to `dst` and checks, that the hash of `dst` matches the one of `src`
+///
+/// # Errors
+/// - [`IoError`] with kind [`ErrorKind::InvalidData`] when the hashes don't match, `dst` is left as is
+/// - relays any Errors from io calls
+#[cfg(feature = "hash")]
+pub async fn copy_verified(
+    src: impl AsRef<Path> + Send,
+    dst: impl AsRef<Path> + Send,
+) -> std::io::Result<()> {
+    inner_copy_verified(src.as_ref(), dst.as_ref(), |_| Ok(())).await
+}
+#[cfg(feature = "hash")]
+async fn inner_copy_verified(
+    src: &Path,
+    dst: &Path,
+    after_copy: impl FnOnce(&Path) -> std::io::Result<()> + Send,
+) -> std::io::Result<()> {
+    const ALGO: HashAlgorithm = HashAlgorithm::Sha256;
+    tokio::fs::copy(src, dst).await?;
+    after_copy(dst)?;
+    if hash_file(src, ALGO).await? == hash_file(dst, ALGO).await? {
+        Ok(())
+    } else {
+        Err(IoError::new(
+            ErrorKind::InvalidData,
+            format!(
+                "copy of {} to {} is corrupted",
+                src.display(),
+                dst.display()
+            ),
+        ))
+    }
+}
+
 /// a Wrapper, that creates a copy of a file and removes it, when dropped
 pub struct TmpFile {
     path: PathBuf,
@@ -103,7 +258,7 @@ impl TmpFile {
         match std::fs::metadata(&path) {
             Ok(_) => Err(IoError::new(
                 ErrorKind::AlreadyExists,
-                format!("there is already a file at {path:?}"),
+                format!("there is already a file at {}", path.display()),
             )),
             Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
             Err(error) => Err(error),
@@ -121,7 +276,7 @@ impl TmpFile {
         match std::fs::metadata(&path) {
             Ok(_) => Err(IoError::new(
                 ErrorKind::AlreadyExists,
-                format!("there is already a file at {path:?}"),
+                format!("there is already a file at {}", path.display()),
             )),
             Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
             Err(error) => Err(error),
@@ -129,6 +284,15 @@ impl TmpFile {
         let _ = std::fs::File::create(&path)?;
         Ok(Self::new(path))
     }
+    /// creates a new file called `name` in `dir`, see [`Self::new_empty`]
+    ///
+    /// the file may outlive `dir`, it is then already removed with it
+    ///
+    /// # Errors
+    /// see [`Self::new_empty`]
+    pub fn new_in(dir: &TmpDir, name: impl AsRef<Path>) -> Result<Self, IoError> {
+        Self::new_empty(dir.path().join(name))
+    }
     fn remove(&mut self) -> Result<(), IoError> {
         if !self.is_removed {
             std::fs::remove_file(&self.path)?;
@@ -137,7 +301,7 @@ impl TmpFile {
         Ok(())
     }
     /// mark this file as already removed
-    pub fn was_removed(&mut self) {
+    pub const fn was_removed(&mut self) {
         self.is_removed = true;
     }
 }
@@ -149,10 +313,112 @@ impl AsRef<std::path::Path> for TmpFile {
 }
 impl Drop for TmpFile {
     fn drop(&mut self) {
-        self.remove().unwrap();
+        // the file may already be gone with a surrounding TmpDir
+        match self.remove() {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                warn!("couldn't remove {}: {err}", self.path.display());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// a Wrapper, that creates a directory and recursivly removes it, when dropped
+pub struct TmpDir {
+    path: PathBuf,
+    is_persisted: bool,
+}
+impl TmpDir {
+    /// creates a new directory at `path` and returns a [`TmpDir`] pointed to `path`
+    ///
+    /// # Errors
+    /// - [`IoError`] with kind [`ErrorKind::AlreadyExists`] when there is something at `path`
+    /// - will relay any error from [creating the directory](std::fs::create_dir)
+    pub fn new(path: PathBuf) -> Result<Self, IoError> {
+        std::fs::create_dir(&path)?;
+        Ok(Self {
+            path,
+            is_persisted: false,
+        })
+    }
+    /// the path of the directory
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// keeps the directory and its content and returns its path
+    pub fn persist(mut self) -> PathBuf {
+        self.is_persisted = true;
+        std::mem::take(&mut self.path)
+    }
+}
+impl AsRef<std::path::Path> for TmpDir {
+    fn as_ref(&self) -> &std::path::Path {
+        &self.path
+    }
+}
+impl Drop for TmpDir {
+    fn drop(&mut self) {
+        if self.is_persisted {
+            return;
+        }
+        match std::fs::remove_dir_all(&self.path) {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                warn!("couldn't remove {}: {err}", self.path.display());
+            }
+            _ => {}
+        }
     }
 }
 
+/// counts the \n in the file at `path` without loading it into memory
+/// `count_unterminated` counts any content after the last \n as an extra line
+///
+/// # Errors
+/// relays any Errors from io calls
+#[allow(clippy::naive_bytecount)] // not worth another dependency
+pub async fn count_lines(
+    path: impl AsRef<Path>,
+    count_unterminated: bool,
+) -> std::io::Result<usize> {
+    const NEW_LINE: u8 = b"\n"[0];
+
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut buf = [0u8; 8 * 1024];
+    let mut lines = 0;
+    let mut last = None;
+    loop {
+        match file.read(&mut buf).await? {
+            0 => break,
+            bytes_read => {
+                lines += buf[..bytes_read]
+                    .iter()
+                    .filter(|&&byte| byte == NEW_LINE)
+                    .count();
+                last = Some(buf[bytes_read - 1]);
+            }
+        }
+    }
+    if count_unterminated && last.is_some_and(|it| it != NEW_LINE) {
+        lines += 1;
+    }
+    Ok(lines)
+}
+
+/// reads the last `k` bytes of the file at `path` without reading the rest
+/// gives the whole file when it is shorter than `k`
+///
+/// # Errors
+/// relays any Errors from io calls
+pub async fn read_tail(path: impl AsRef<Path>, k: usize) -> std::io::Result<Vec<u8>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let len = file.metadata().await?.len();
+    let start = len.saturating_sub(k as u64);
+    file.seek(std::io::SeekFrom::Start(start)).await?;
+    let mut buf = Vec::with_capacity((len - start) as usize);
+    file.read_to_end(&mut buf).await?;
+    Ok(buf)
+}
+
 /// assumes linux style \n and an extra newline at the end
 /// leaves pointer at the end of the file
 ///
@@ -220,6 +486,24 @@ async fn inner_truncate_last_lines(
     Ok(())
 }
 
+#[tokio::test]
+async fn read_tail_test() {
+    assert_eq!(
+        b"line 3\n".to_vec(),
+        read_tail("./res/truncate.txt", 7).await.unwrap(),
+        "failed longer file"
+    );
+    assert_eq!(
+        tokio::fs::read("./res/truncate.txt").await.unwrap(),
+        read_tail("./res/truncate.txt", 100).await.unwrap(),
+        "failed shorter file"
+    );
+    assert!(
+        read_tail("./res/truncate.txt", 0).await.unwrap().is_empty(),
+        "failed zero bytes"
+    );
+}
+
 #[tokio::test]
 async fn truncate_const_lines() {
     async fn helper<const N: usize>() -> String {
@@ -271,3 +555,231 @@ async fn truncate_lines() {
     assert_eq!("\n", helper(3).await);
     assert_eq!("\n", helper(4).await);
 }
+
+#[tokio::test]
+async fn move_dry_run() {
+    const DRY_RUN: MoveOptions = MoveOptions {
+        dry_run: true,
+        create_dst: false,
+    };
+    move_file("./res/truncate.txt", "./res", true)
+        .await
+        .unwrap();
+    assert!(
+        matches!(
+            inner_move_file("./res/missing.txt".as_ref(), "./res".as_ref(), DRY_RUN).await,
+            Err(MoveError::FileNotFound)
+        ),
+        "moved missing file"
+    );
+    assert!(
+        matches!(
+            inner_move_file("./res".as_ref(), "./res".as_ref(), DRY_RUN).await,
+            Err(MoveError::FileNotFound)
+        ),
+        "moved a folder"
+    );
+    assert!(
+        matches!(
+            inner_move_file("./res/truncate.txt".as_ref(), "./missing".as_ref(), DRY_RUN).await,
+            Err(MoveError::TargetNotFound)
+        ),
+        "moved into missing folder"
+    );
+    assert!(
+        matches!(
+            inner_move_file(
+                "./res/truncate.txt".as_ref(),
+                "./res/truncate.txt".as_ref(),
+                DRY_RUN
+            )
+            .await,
+            Err(MoveError::TargetNotFound)
+        ),
+        "moved into a file"
+    );
+    assert!(
+        matches!(
+            inner_move_file(
+                "./res/truncate.txt/file.txt".as_ref(),
+                "./res".as_ref(),
+                DRY_RUN
+            )
+            .await,
+            Err(MoveError::OtherIO(err)) if err.kind() == ErrorKind::NotADirectory
+        ),
+        "didn't relay other error"
+    );
+}
+
+#[tokio::test]
+async fn move_files_test() {
+    let results = move_files(
+        [
+            (PathBuf::from("./res/truncate.txt"), PathBuf::from("./res")),
+            (PathBuf::from("./res/missing.txt"), PathBuf::from("./res")),
+        ],
+        true,
+    )
+    .await;
+    assert_eq!(2, results.len(), "missing results");
+    assert_eq!(
+        Some(&PathBuf::from("./res/truncate.txt")),
+        results[0].as_ref().ok(),
+        "failed valid move"
+    );
+    assert!(
+        matches!(
+            &results[1],
+            Err((MoveError::FileNotFound, file, _)) if file == Path::new("./res/missing.txt")
+        ),
+        "didn't report invalid move"
+    );
+}
+
+#[tokio::test]
+async fn move_create_dst() {
+    let dir = TmpDir::new(PathBuf::from("./res/.move_create_dst")).unwrap();
+    let mut file = TmpFile::new_in(&dir, "file.txt").unwrap();
+    let dst = dir.path().join("nested/sub");
+    let create = MoveOptions {
+        dry_run: false,
+        create_dst: true,
+    };
+    assert!(
+        matches!(
+            inner_move_file(file.as_ref(), &dst, MoveOptions::default()).await,
+            Err(MoveError::TargetNotFound)
+        ),
+        "moved into missing folder"
+    );
+    let dry_create = MoveOptions {
+        dry_run: true,
+        ..create
+    };
+    inner_move_file(file.as_ref(), &dst, dry_create)
+        .await
+        .unwrap();
+    assert!(!dst.exists(), "created folder in dry run");
+
+    move_file_with(file.as_ref(), &dst, create).await.unwrap();
+    file.was_removed();
+    assert!(dst.join("file.txt").is_file(), "didn't move file");
+    assert!(
+        matches!(
+            inner_move_file(file.as_ref(), &dir.path().join("other"), create).await,
+            Err(MoveError::FileNotFound)
+        ),
+        "moved missing file"
+    );
+    assert!(
+        !dir.path().join("other").exists(),
+        "created folder for missing file"
+    );
+}
+
+#[tokio::test]
+async fn ensure_parent_dirs_test() {
+    let dir = TmpDir::new(PathBuf::from("./res/.ensure_parent_dirs")).unwrap();
+    let file = dir.path().join("nested/sub/file.txt");
+    ensure_parent_dirs(&file).await.unwrap();
+    assert!(
+        dir.path().join("nested/sub").is_dir(),
+        "didn't create parents"
+    );
+    assert!(!file.exists(), "created the file itself");
+    ensure_parent_dirs(&file).await.unwrap();
+}
+
+#[tokio::test]
+async fn count_lines_test() {
+    assert_eq!(3, count_lines("./res/truncate.txt", false).await.unwrap());
+    assert_eq!(
+        3,
+        count_lines("./res/truncate.txt", true).await.unwrap(),
+        "counted terminated line twice"
+    );
+
+    let file = TmpFile::new_empty(PathBuf::from("./res/.count_lines.txt")).unwrap();
+    assert_eq!(
+        0,
+        count_lines(&file, true).await.unwrap(),
+        "failed empty file"
+    );
+    std::fs::write(&file, "line 1\nline 2").unwrap();
+    assert_eq!(
+        1,
+        count_lines(&file, false).await.unwrap(),
+        "counted unterminated line"
+    );
+    assert_eq!(
+        2,
+        count_lines(&file, true).await.unwrap(),
+        "didn't count unterminated line"
+    );
+}
+
+#[test]
+fn tmp_dir() {
+    let dir = TmpDir::new(PathBuf::from("./res/.tmp_dir")).unwrap();
+    let path = dir.path().to_path_buf();
+    let mut file = TmpFile::new_in(&dir, "file.txt").unwrap();
+    assert!(file.as_ref().is_file(), "didn't create file");
+    file.was_removed(); // left for the dir to clean up
+    drop(file);
+    std::fs::create_dir(path.join("sub")).unwrap();
+    std::fs::write(path.join("sub/other.txt"), "data").unwrap();
+    drop(dir);
+    assert!(!path.exists(), "didn't remove dir");
+
+    let dir = TmpDir::new(PathBuf::from("./res/.tmp_dir_persist")).unwrap();
+    let path = dir.persist();
+    assert!(path.is_dir(), "didn't persist dir");
+    std::fs::remove_dir(path).unwrap();
+}
+
+#[test]
+fn tmp_file_outlives_dir() {
+    let dir = TmpDir::new(PathBuf::from("./res/.tmp_file_outlives_dir")).unwrap();
+    let path = dir.path().to_path_buf();
+    let file = TmpFile::new_in(&dir, "file.txt").unwrap();
+    drop(dir);
+    assert!(!path.exists(), "didn't remove dir");
+    drop(file); // must not panic for the already removed file
+
+    let dir = TmpDir::new(PathBuf::from("./res/.tmp_dir_removed")).unwrap();
+    std::fs::remove_dir(dir.path()).unwrap();
+    drop(dir); // must not panic for the already removed dir
+}
+
+#[cfg(feature = "hash")]
+#[tokio::test]
+async fn hash_sha256() {
+    use itertools::Itertools;
+    let digest = hash_file("./res/truncate.txt", HashAlgorithm::Sha256)
+        .await
+        .unwrap();
+    assert_eq!(
+        "6ca9d5edb68deaadc1d3130c5fc3ec36e12db72ad54e93edcd63bdfb40a83300",
+        digest.iter().map(|byte| format!("{byte:02x}")).join("")
+    );
+}
+
+#[cfg(feature = "hash")]
+#[tokio::test]
+async fn copy_verified_test() {
+    let dst = TmpFile::new(PathBuf::from("./res/.copy_verified.txt"));
+    copy_verified("./res/truncate.txt", &dst).await.unwrap();
+    assert_eq!(
+        "line 1\nline 2\nline 3\n",
+        tokio::fs::read_to_string(&dst).await.unwrap()
+    );
+
+    let dst = TmpFile::new(PathBuf::from("./res/.copy_verified_corrupted.txt"));
+    let err = inner_copy_verified("./res/truncate.txt".as_ref(), dst.as_ref(), |dst| {
+        std::fs::write(dst, "corrupted")
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(ErrorKind::InvalidData, err.kind());
+}
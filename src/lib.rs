@@ -29,6 +29,49 @@ pub mod str {
     pub const fn compare_char(a: char, b: char, ignore_case: bool) -> bool {
         (ignore_case && a.eq_ignore_ascii_case(&b)) || a == b
     }
+
+    /// how the case of chars is respected, when comparing them
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub enum CaseSensitivity {
+        /// chars need to be equal
+        #[default]
+        Sensitive,
+        /// the case of ascii letters is ignored, like in [`char::eq_ignore_ascii_case`]
+        Ascii,
+        /// the case of all chars is ignored, see [`Self::fold`].
+        /// Unlike [`Self::Ascii`] this folds chars like 'σ' and 'Σ' or 'ı' and 'I'
+        #[cfg(feature = "unicode")]
+        Unicode,
+    }
+    impl CaseSensitivity {
+        /// compares `a` and `b` with respect to `self`
+        #[allow(clippy::missing_const_for_fn)] // folding the case isn't const
+        pub fn eq_char(self, a: char, b: char) -> bool {
+            match self {
+                Self::Sensitive => a == b,
+                Self::Ascii => a.eq_ignore_ascii_case(&b),
+                #[cfg(feature = "unicode")]
+                Self::Unicode => a == b || Self::fold(a).eq(Self::fold(b)),
+            }
+        }
+        /// folds the case of `c` by mapping it to uppercase and then to lowercase
+        ///
+        /// this also matches a final sigma with a sigma, unlike only using [`char::to_lowercase`]
+        #[cfg(feature = "unicode")]
+        pub fn fold(c: char) -> impl Iterator<Item = char> {
+            c.to_uppercase().flat_map(char::to_lowercase)
+        }
+    }
+    impl From<bool> for CaseSensitivity {
+        /// `true` ignores the ascii case like [`compare_char`]
+        fn from(ignore_case: bool) -> Self {
+            if ignore_case {
+                Self::Ascii
+            } else {
+                Self::Sensitive
+            }
+        }
+    }
 }
 
 /// collections
@@ -88,6 +131,12 @@ pub mod collections {
             // SAFETY: assert_slice checks for Zero Size of T and overflows of (N+M)*size_t
             unsafe { std::slice::from_raw_parts_mut(self.a.as_mut_ptr(), N + M) }
         }
+        /// flattens `self` into a single array, fails to compile if `L` isn't `N + M`
+        pub fn into_array<const L: usize>(self) -> [T; L] {
+            const { assert!(L == N + M, "L needs to be N + M") };
+            let mut iter = self.a.into_iter().chain(self.b);
+            std::array::from_fn(|_| iter.next().unwrap_or_else(|| unreachable!()))
+        }
     }
 
     impl<const N: usize, const M: usize, T> std::ops::Index<usize> for ArrayNPM<N, M, T> {
@@ -126,6 +175,18 @@ pub mod collections {
             assert_eq!([10, 11, 12], data.a, "failed to write to a");
             assert_eq!([13, 14, 15], data.b, "failed to write to b");
         }
+        #[test]
+        fn into_array() {
+            let data = ArrayNPM::<3, 3, u8>::from_fn(|it| it as u8);
+            assert_eq!([0, 1, 2, 3, 4, 5], data.into_array(), "wrong order");
+
+            let data = ArrayNPM::<2, 0, String>::from_fn(|it| it.to_string());
+            assert_eq!(
+                ["0", "1"],
+                data.into_array(),
+                "failed with empty second part"
+            );
+        }
     }
 }
 
@@ -137,6 +198,12 @@ pub mod collections {
 ///     <condition>,
 ///     <Error>
 /// }
+/// the optional else block is run before returning the Error
+/// require!{
+///     <condition>,
+///     <Error>,
+///     else { <cleanup> }
+/// }
 #[macro_export]
 macro_rules! require {
     ($cond:expr, $err:expr) => {
@@ -144,6 +211,31 @@ macro_rules! require {
             return Err($err);
         }
     };
+    ($cond:expr, $err:expr, else $cleanup:block) => {
+        if !$cond {
+            $cleanup
+            return Err($err);
+        }
+    };
+}
+#[cfg(test)]
+mod tests {
+    fn checked(cond: bool, cleaned: &mut usize) -> Result<(), &'static str> {
+        require!(cond, "failed", else { *cleaned += 1; });
+        Ok(())
+    }
+    #[test]
+    fn require_else() {
+        let mut cleaned = 0;
+        assert_eq!(Ok(()), checked(true, &mut cleaned), "failed with true");
+        assert_eq!(0, cleaned, "cleaned up with true");
+        assert_eq!(
+            Err("failed"),
+            checked(false, &mut cleaned),
+            "failed with false"
+        );
+        assert_eq!(1, cleaned, "didn't clean up with false");
+    }
 }
 
 /// common utilitys for argparsing
@@ -151,7 +243,34 @@ pub mod args {
     #![cfg(feature = "args")]
     /// common utilitys for input managing
     pub mod input {
+        use std::io::BufRead;
+
         use clap::Args;
+        use thiserror::Error;
+
+        /// An Error that can happen, when asking for consent
+        #[derive(Debug, Error)]
+        pub enum ConsentError {
+            /// no valid answer was given in the allowed number of trys
+            #[error("no valid answer was given")]
+            Cancelled,
+            /// the input was closed before a valid answer was given
+            #[error("input was closed")]
+            Eof,
+            /// the input couldn't be read
+            #[error(transparent)]
+            Io(#[from] std::io::Error),
+        }
+        /// [`ConsentError::Io`] is compared by the [kind](std::io::Error::kind) of the error
+        impl PartialEq for ConsentError {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    (Self::Io(a), Self::Io(b)) => a.kind() == b.kind(),
+                    _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+                }
+            }
+        }
+        impl Eq for ConsentError {}
 
         #[derive(Args, Debug, Clone, Copy)]
         #[group(required = false, multiple = false)]
@@ -174,42 +293,118 @@ pub mod args {
             pub fn new(bools: impl Into<Option<bool>>, trys: impl Into<Option<u8>>) -> Self {
                 let bools: Option<_> = bools.into();
                 Self {
-                    yes: bools.is_some(),
+                    yes: bools.is_some_and(|it| it),
                     no: bools.is_some_and(|it| !it),
                     trys: trys.into().unwrap_or(3),
                 }
             }
 
             #[inline]
-            #[allow(clippy::needless_pass_by_value)]
             fn inner_read<T>(
+                msg: impl AsRef<str>,
+                default: impl Into<Option<T>>,
+                retry_msg: Option<impl AsRef<str>>,
+                map: impl FnMut(String) -> Option<T>,
+                trys: impl IntoIterator<Item = u8>,
+            ) -> Result<T, ConsentError> {
+                Self::inner_read_from(
+                    &mut std::io::stdin().lock(),
+                    msg,
+                    default,
+                    retry_msg,
+                    map,
+                    trys,
+                )
+            }
+            #[allow(clippy::needless_pass_by_value)]
+            fn inner_read_from<T>(
+                input: &mut impl BufRead,
                 msg: impl AsRef<str>,
                 default: impl Into<Option<T>>,
                 retry_msg: Option<impl AsRef<str>>,
                 mut map: impl FnMut(String) -> Option<T>,
                 trys: impl IntoIterator<Item = u8>,
-            ) -> Option<T> {
+            ) -> Result<T, ConsentError> {
+                let msg = msg.as_ref();
+                let retry_msg = retry_msg.as_ref().map(std::convert::AsRef::as_ref);
+                let mut default = default.into();
+
+                print!("{msg}");
+                for _ in trys {
+                    let mut rin = String::new();
+                    let read = input.read_line(&mut rin);
+                    if let Some(result) =
+                        Self::handle_line(read, &rin, &mut default, msg, retry_msg, &mut map)
+                    {
+                        return result;
+                    }
+                }
+                Err(ConsentError::Cancelled)
+            }
+            /// like [`Self::inner_read_from`], but reads without blocking the executor
+            #[cfg(feature = "io")]
+            #[allow(clippy::needless_pass_by_value)]
+            async fn inner_read_from_async<T: Send>(
+                input: &mut (impl tokio::io::AsyncBufRead + Unpin + Send),
+                msg: impl AsRef<str> + Send,
+                default: impl Into<Option<T>> + Send,
+                retry_msg: Option<impl AsRef<str> + Send>,
+                mut map: impl FnMut(String) -> Option<T> + Send,
+                trys: impl IntoIterator<Item = u8> + Send,
+            ) -> Result<T, ConsentError> {
+                use tokio::io::AsyncBufReadExt;
                 let msg = msg.as_ref();
                 let retry_msg = retry_msg.as_ref().map(std::convert::AsRef::as_ref);
-                let default = default.into();
+                let mut default = default.into();
 
                 print!("{msg}");
                 for _ in trys {
-                    let rin: String = text_io::read!("{}\n");
-                    if default.is_some() && rin.is_empty() {
-                        return default;
+                    let mut rin = String::new();
+                    let read = input.read_line(&mut rin).await;
+                    if let Some(result) =
+                        Self::handle_line(read, &rin, &mut default, msg, retry_msg, &mut map)
+                    {
+                        return result;
                     }
-                    match (map(rin), retry_msg) {
-                        (Some(t), _) => return Some(t),
-                        (None, Some(retry_msg)) => println!("{retry_msg}"),
-                        (None, None) => print!("{msg}"),
+                }
+                Err(ConsentError::Cancelled)
+            }
+            /// returns the result of reading one line, or `None` if another line needs to be read
+            fn handle_line<T>(
+                read: std::io::Result<usize>,
+                rin: &str,
+                default: &mut Option<T>,
+                msg: &str,
+                retry_msg: Option<&str>,
+                map: &mut impl FnMut(String) -> Option<T>,
+            ) -> Option<Result<T, ConsentError>> {
+                match read {
+                    Ok(0) => return Some(default.take().ok_or(ConsentError::Eof)),
+                    Ok(_) => {}
+                    Err(err) => return Some(Err(err.into())),
+                }
+                let rin = rin.trim_end_matches(['\n', '\r']).to_owned();
+                if rin.is_empty() {
+                    if let Some(default) = default.take() {
+                        return Some(Ok(default));
                     }
                 }
+                match (map(rin), retry_msg) {
+                    (Some(t), _) => return Some(Ok(t)),
+                    (None, Some(retry_msg)) => println!("{retry_msg}"),
+                    (None, None) => print!("{msg}"),
+                }
                 None
             }
+            /// an unbuffered async stdin, so no input after the read line is lost
+            #[cfg(feature = "io")]
+            fn async_stdin() -> tokio::io::BufReader<tokio::io::Stdin> {
+                tokio::io::BufReader::with_capacity(1, tokio::io::stdin())
+            }
 
             const DEFAULT_RETRY_MSG: &'static str = "couldn't parse that, please try again: ";
             /// read userinput as a String
+            /// returns an empty String, when the input was closed or couldn't be read
             pub fn read(msg: impl AsRef<str>, default: Option<String>) -> String {
                 Self::inner_read(
                     msg,
@@ -218,9 +413,37 @@ pub mod args {
                     Some,
                     std::iter::once(1),
                 )
-                .unwrap_or_else(|| unreachable!())
+                .unwrap_or_default()
+            }
+            /// like [`Self::read`], but reads without blocking the executor
+            #[cfg(feature = "io")]
+            pub async fn read_async(
+                msg: impl AsRef<str> + Send,
+                default: Option<String>,
+            ) -> String {
+                Self::read_from_async(&mut Self::async_stdin(), msg.as_ref(), default).await
+            }
+            #[cfg(feature = "io")]
+            async fn read_from_async(
+                input: &mut (impl tokio::io::AsyncBufRead + Unpin + Send),
+                msg: &str,
+                default: Option<String>,
+            ) -> String {
+                Self::inner_read_from_async(
+                    input,
+                    msg,
+                    default,
+                    Some(Self::DEFAULT_RETRY_MSG),
+                    Some,
+                    std::iter::once(1),
+                )
+                .await
+                .unwrap_or_default()
             }
             /// read userinput and map it. Retrys to read until `map` returns `Some`
+            ///
+            /// # Panics
+            /// panics when the input was closed or couldn't be read before `map` returned `Some`
             pub fn map_read<T>(
                 msg: impl AsRef<str>,
                 default: impl Into<Option<T>>,
@@ -228,7 +451,32 @@ pub mod args {
                 map: impl FnMut(String) -> Option<T>,
             ) -> T {
                 Self::inner_read(msg, default, retry_msg, map, 1..)
-                    .unwrap_or_else(|| unreachable!())
+                    .unwrap_or_else(|err| panic!("failed to read input: {err}"))
+            }
+            /// read userinput and parse it, shows `default` in the prompt as `msg [default]: `
+            /// returns `default` on empty input or when the input was closed or couldn't be read. Retrys to read until the input can be parsed
+            pub fn read_with_default<T>(msg: impl AsRef<str>, default: T) -> T
+            where
+                T: std::fmt::Display + std::str::FromStr,
+            {
+                Self::read_with_default_from(&mut std::io::stdin().lock(), msg.as_ref(), default)
+            }
+            fn read_with_default_from<T>(input: &mut impl BufRead, msg: &str, default: T) -> T
+            where
+                T: std::fmt::Display + std::str::FromStr,
+            {
+                // `default` is kept out of the read, so it can also be returned on read errors
+                Self::inner_read_from(
+                    input,
+                    format!("{msg} [{default}]: "),
+                    Some(None),
+                    Some(Self::DEFAULT_RETRY_MSG),
+                    |it| it.parse().ok().map(Some),
+                    1..,
+                )
+                .ok()
+                .flatten()
+                .unwrap_or(default)
             }
             // TODO remove trys from Self
             /// read userinput and map it. Retrys to read until `map` returns `Some` or until self.trys
@@ -238,36 +486,176 @@ pub mod args {
                 default: Option<T>,
                 map: impl FnMut(String) -> Option<T>,
             ) -> Option<T> {
-                Self::inner_read(
+                self.try_read_from(&mut std::io::stdin().lock(), msg, default, map)
+            }
+            fn try_read_from<T>(
+                self,
+                input: &mut impl BufRead,
+                msg: impl AsRef<str>,
+                default: Option<T>,
+                map: impl FnMut(String) -> Option<T>,
+            ) -> Option<T> {
+                Self::inner_read_from(
+                    input,
                     msg,
                     default,
                     Some(Self::DEFAULT_RETRY_MSG),
                     map,
-                    1..self.trys,
+                    0..self.trys,
+                )
+                .ok()
+            }
+            /// read userinput twice and return it, when both entries match.
+            /// Retrys until self.trys
+            ///
+            /// # Errors
+            /// - [`ConsentError::Cancelled`] when no matching entries where given in `self.trys` trys
+            /// - [`ConsentError::Eof`] when the input was closed
+            /// - [`ConsentError::Io`] when the input couldn't be read
+            pub fn read_confirmed(
+                self,
+                msg: impl AsRef<str>,
+                confirm_msg: impl AsRef<str>,
+            ) -> Result<String, ConsentError> {
+                self.read_confirmed_from(
+                    &mut std::io::stdin().lock(),
+                    msg.as_ref(),
+                    confirm_msg.as_ref(),
+                )
+            }
+            fn read_confirmed_from(
+                self,
+                input: &mut impl BufRead,
+                msg: &str,
+                confirm_msg: &str,
+            ) -> Result<String, ConsentError> {
+                let mut read = |msg| {
+                    Self::inner_read_from(input, msg, None, None::<&str>, Some, std::iter::once(1))
+                };
+                for _ in 0..self.trys {
+                    let first = read(msg)?;
+                    if read(confirm_msg)? == first {
+                        return Ok(first);
+                    }
+                    println!("entries didn't match, please try again");
+                }
+                Err(ConsentError::Cancelled)
+            }
+            /// prints `options` as a numbered list and reads the number of one of them.
+            /// Retrys until self.trys
+            ///
+            /// returns the index of the chosen option, or `None` on empty input, closed input or when no valid number was given
+            pub fn choose_numbered<T: std::fmt::Display>(
+                self,
+                msg: impl AsRef<str>,
+                options: &[T],
+            ) -> Option<usize> {
+                self.choose_numbered_from(&mut std::io::stdin().lock(), msg.as_ref(), options)
+            }
+            fn choose_numbered_from<T: std::fmt::Display>(
+                self,
+                input: &mut impl BufRead,
+                msg: &str,
+                options: &[T],
+            ) -> Option<usize> {
+                for (i, option) in options.iter().enumerate() {
+                    println!("{}) {option}", i + 1);
+                }
+                Self::inner_read_from(
+                    input,
+                    format!("{msg}: "),
+                    Some(None),
+                    Some(format!(
+                        "please enter a number between 1 and {}: ",
+                        options.len()
+                    )),
+                    |it| {
+                        it.trim()
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&it| (1..=options.len()).contains(&it))
+                            .map(|it| Some(it - 1))
+                    },
+                    0..self.trys,
                 )
+                .ok()
+                .flatten()
             }
 
             #[must_use]
-            #[momo::momo]
             /// asks user for consent if no default is set
+            /// defaults to `false` when no valid answer was given
             pub fn ask_consent(self, msg: impl AsRef<str>) -> bool {
-                if self.yes || self.no {
-                    return self.yes;
-                }
-                self.try_read(format!("{msg} [y/n]: "), None, |it| {
-                    if ["y", "yes", "j", "ja"].contains(&it.as_str()) {
-                        Some(true)
-                    } else if ["n", "no", "nein"].contains(&it.as_str()) {
-                        Some(false)
-                    } else {
-                        None
-                    }
-                })
-                .unwrap_or_else(|| {
+                self.ask_consent_result(msg).unwrap_or_else(|_| {
                     log::info!("probably not");
                     false
                 })
             }
+            /// asks user for consent if no default is set
+            ///
+            /// # Errors
+            /// - [`ConsentError::Cancelled`] when no valid answer was given in `self.trys` trys
+            /// - [`ConsentError::Eof`] when the input was closed
+            /// - [`ConsentError::Io`] when the input couldn't be read
+            pub fn ask_consent_result(self, msg: impl AsRef<str>) -> Result<bool, ConsentError> {
+                self.ask_consent_from(&mut std::io::stdin().lock(), msg.as_ref())
+            }
+            fn ask_consent_from(
+                self,
+                input: &mut impl BufRead,
+                msg: &str,
+            ) -> Result<bool, ConsentError> {
+                if self.yes || self.no {
+                    return Ok(self.yes);
+                }
+                Self::inner_read_from(
+                    input,
+                    format!("{msg} [y/n]: "),
+                    None,
+                    Some(Self::DEFAULT_RETRY_MSG),
+                    Self::parse_consent,
+                    0..self.trys,
+                )
+            }
+            /// like [`Self::ask_consent`], but reads without blocking the executor
+            #[cfg(feature = "io")]
+            pub async fn ask_consent_async(self, msg: impl AsRef<str> + Send) -> bool {
+                self.ask_consent_from_async(&mut Self::async_stdin(), msg.as_ref())
+                    .await
+                    .unwrap_or_else(|_| {
+                        log::info!("probably not");
+                        false
+                    })
+            }
+            #[cfg(feature = "io")]
+            async fn ask_consent_from_async(
+                self,
+                input: &mut (impl tokio::io::AsyncBufRead + Unpin + Send),
+                msg: &str,
+            ) -> Result<bool, ConsentError> {
+                if self.yes || self.no {
+                    return Ok(self.yes);
+                }
+                Self::inner_read_from_async(
+                    input,
+                    format!("{msg} [y/n]: "),
+                    None,
+                    Some(Self::DEFAULT_RETRY_MSG),
+                    Self::parse_consent,
+                    0..self.trys,
+                )
+                .await
+            }
+            #[allow(clippy::needless_pass_by_value)]
+            fn parse_consent(it: String) -> Option<bool> {
+                if ["y", "yes", "j", "ja"].contains(&it.as_str()) {
+                    Some(true)
+                } else if ["n", "no", "nein"].contains(&it.as_str()) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
 
             #[must_use]
             /// read userinput as a String.
@@ -275,25 +663,307 @@ pub mod args {
             /// Uses `suggestor` for suggestions
             ///
             /// # Panics
-            /// unwraps undocumented Result of [`inquire::prompts::text::Text::prompt`]
+            /// panics when [`Self::try_read_with_suggestion`] returns an error
             pub fn read_with_suggestion(
                 msg: impl AsRef<str>,
                 initial: Option<&str>,
-                mut suggestor: impl autocompleter::Autocomplete,
+                suggestor: impl autocompleter::Autocomplete,
             ) -> String {
-                let mut text = inquire::Text::new(msg.as_ref());
+                Self::try_read_with_suggestion(msg, initial, suggestor).unwrap()
+            }
+            /// read userinput as a String.
+            /// Starts with `initial`
+            /// Uses `suggestor` for suggestions
+            ///
+            /// # Errors
+            /// relays any error of [`inquire::prompts::text::Text::prompt`], like a canceled prompt
+            pub fn try_read_with_suggestion(
+                msg: impl AsRef<str>,
+                initial: Option<&str>,
+                suggestor: impl autocompleter::Autocomplete,
+            ) -> Result<String, inquire::InquireError> {
+                Self::inner_read_with_suggestion(
+                    msg.as_ref(),
+                    initial,
+                    suggestor,
+                    inquire::Text::prompt,
+                )
+            }
+            /// like [`Self::try_read_with_suggestion`], but `prompt` runs the [`inquire::Text`]
+            fn inner_read_with_suggestion<'a>(
+                msg: &'a str,
+                initial: Option<&'a str>,
+                mut suggestor: impl autocompleter::Autocomplete,
+                prompt: impl FnOnce(inquire::Text<'a>) -> Result<String, inquire::InquireError>,
+            ) -> Result<String, inquire::InquireError> {
+                let mut text = inquire::Text::new(msg);
                 text.initial_value = initial;
                 // SAFTY: the reference to suggestor must be kept alive until ac is dropped. black-box should do this.
                 let ac = unsafe { autocompleter::BorrowCompleter::new(&mut suggestor) };
-                let res = text.with_autocomplete(ac).prompt().unwrap();
+                let res = prompt(text.with_autocomplete(ac));
                 drop(std::hint::black_box(suggestor));
                 res
             }
         }
 
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn consent() {
+                let inputs = Inputs::new(None, 3);
+                assert_eq!(
+                    Ok(true),
+                    inputs.ask_consent_from(&mut &b"maybe\ny\n"[..], "msg"),
+                    "failed with retry"
+                );
+                assert_eq!(
+                    Ok(false),
+                    inputs.ask_consent_from(&mut &b"no\n"[..], "msg"),
+                    "failed with no"
+                );
+                assert_eq!(
+                    Ok(false),
+                    Inputs::new(false, 3).ask_consent_from(&mut &b"y\n"[..], "msg"),
+                    "failed to use default"
+                );
+            }
+            #[cfg(feature = "io")]
+            #[tokio::test]
+            async fn consent_async() {
+                let inputs = Inputs::new(None, 3);
+                assert_eq!(
+                    Ok(true),
+                    inputs
+                        .ask_consent_from_async(&mut &b"maybe\ny\n"[..], "msg")
+                        .await,
+                    "failed with retry"
+                );
+                assert_eq!(
+                    Err(ConsentError::Cancelled),
+                    inputs
+                        .ask_consent_from_async(&mut &b"maybe\nwhat\nperhaps\ny\n"[..], "msg")
+                        .await,
+                    "didn't stop after trys"
+                );
+                assert_eq!(
+                    Err(ConsentError::Eof),
+                    inputs.ask_consent_from_async(&mut &b""[..], "msg").await,
+                    "failed with eof"
+                );
+                assert_eq!(
+                    Ok(false),
+                    Inputs::new(false, 3)
+                        .ask_consent_from_async(&mut &b"y\n"[..], "msg")
+                        .await,
+                    "ignored default answer"
+                );
+            }
+            #[cfg(feature = "io")]
+            #[tokio::test]
+            async fn read_async() {
+                assert_eq!(
+                    "value",
+                    Inputs::read_from_async(&mut &b"value\n"[..], "msg", None).await,
+                    "failed to read"
+                );
+                assert_eq!(
+                    "default",
+                    Inputs::read_from_async(&mut &b"\n"[..], "msg", Some("default".to_owned()))
+                        .await,
+                    "failed to use default"
+                );
+                assert_eq!(
+                    "",
+                    Inputs::read_from_async(&mut &b""[..], "msg", None).await,
+                    "failed with closed input"
+                );
+            }
+            #[test]
+            fn new() {
+                let inputs = Inputs::new(Some(false), None);
+                assert!(!inputs.yes && inputs.no, "failed always no");
+                let inputs = Inputs::new(Some(true), None);
+                assert!(inputs.yes && !inputs.no, "failed always yes");
+                let inputs = Inputs::new(None, 2);
+                assert!(!inputs.yes && !inputs.no, "set a default answer");
+                assert_eq!(2, inputs.trys, "failed trys");
+            }
+            #[test]
+            fn try_read_retrys() {
+                let inputs = Inputs::new(None, 3);
+                let parse = |it: String| it.parse::<u8>().ok();
+                assert_eq!(
+                    Some(3),
+                    inputs.try_read_from(&mut &b"a\nb\n3\n"[..], "msg", None, parse),
+                    "didn't use all trys"
+                );
+                assert_eq!(
+                    None,
+                    inputs.try_read_from(&mut &b"a\nb\nc\n4\n"[..], "msg", None, parse),
+                    "used more than trys"
+                );
+            }
+            #[test]
+            fn consent_io_error() {
+                assert!(
+                    matches!(
+                        Inputs::new(None, 3).ask_consent_from(&mut &b"\xff\n"[..], "msg"),
+                        Err(ConsentError::Io(err)) if err.kind() == std::io::ErrorKind::InvalidData
+                    ),
+                    "didn't keep io error"
+                );
+            }
+            #[test]
+            fn consent_exhausted() {
+                assert_eq!(
+                    Err(ConsentError::Cancelled),
+                    Inputs::new(None, 3)
+                        .ask_consent_from(&mut &b"maybe\nwhat\nperhaps\ny\n"[..], "msg"),
+                );
+            }
+            #[test]
+            fn consent_eof() {
+                let inputs = Inputs::new(None, 3);
+                assert_eq!(
+                    Err(ConsentError::Eof),
+                    inputs.ask_consent_from(&mut &b""[..], "msg"),
+                    "failed with immediate eof"
+                );
+                assert_eq!(
+                    Err(ConsentError::Eof),
+                    inputs.ask_consent_from(&mut &b"maybe\n"[..], "msg"),
+                    "failed with eof after retry"
+                );
+            }
+
+            #[test]
+            fn read_with_default() {
+                assert_eq!(
+                    5,
+                    Inputs::read_with_default_from(&mut &b"\n"[..], "msg", 5),
+                    "failed with empty input"
+                );
+                assert_eq!(
+                    7,
+                    Inputs::read_with_default_from(&mut &b"7\n"[..], "msg", 5),
+                    "failed to override"
+                );
+                assert_eq!(
+                    7,
+                    Inputs::read_with_default_from(&mut &b"seven\n7\n"[..], "msg", 5),
+                    "failed to retry"
+                );
+                assert_eq!(
+                    5,
+                    Inputs::read_with_default_from(&mut &b""[..], "msg", 5),
+                    "failed with closed input"
+                );
+                assert_eq!(
+                    5,
+                    Inputs::read_with_default_from(&mut &b"\xff\xfe\n"[..], "msg", 5),
+                    "failed with invalid utf-8"
+                );
+            }
+
+            #[test]
+            fn read_confirmed() {
+                let inputs = Inputs::new(None, 2);
+                assert_eq!(
+                    Ok("secret".to_owned()),
+                    inputs.read_confirmed_from(&mut &b"secret\nsecret\n"[..], "msg", "confirm"),
+                    "failed first try"
+                );
+                assert_eq!(
+                    Ok("secret".to_owned()),
+                    inputs.read_confirmed_from(
+                        &mut &b"secret\nsecert\nsecret\nsecret\n"[..],
+                        "msg",
+                        "confirm"
+                    ),
+                    "failed after mismatch"
+                );
+                assert_eq!(
+                    Err(ConsentError::Cancelled),
+                    inputs.read_confirmed_from(&mut &b"a\nb\nc\nd\ne\ne\n"[..], "msg", "confirm"),
+                    "didn't stop after trys"
+                );
+                assert_eq!(
+                    Err(ConsentError::Eof),
+                    inputs.read_confirmed_from(&mut &b"secret\n"[..], "msg", "confirm"),
+                    "failed with closed input"
+                );
+            }
+
+            #[test]
+            fn choose_numbered() {
+                let inputs = Inputs::new(None, 2);
+                let options = ["a", "b", "c"];
+                assert_eq!(
+                    Some(1),
+                    inputs.choose_numbered_from(&mut &b"2\n"[..], "msg", &options),
+                    "failed valid selection"
+                );
+                assert_eq!(
+                    Some(2),
+                    inputs.choose_numbered_from(&mut &b"4\n3\n"[..], "msg", &options),
+                    "failed after out of range"
+                );
+                assert_eq!(
+                    None,
+                    inputs.choose_numbered_from(&mut &b"0\nx\n1\n"[..], "msg", &options),
+                    "didn't stop after trys"
+                );
+                assert_eq!(
+                    None,
+                    inputs.choose_numbered_from(&mut &b"\n1\n"[..], "msg", &options),
+                    "failed empty input"
+                );
+            }
+
+            #[test]
+            fn suggestion_error() {
+                use inquire::InquireError;
+
+                let completer = || {
+                    autocompleter::VecCompleter::from_iter(
+                        ["a"],
+                        crate::str::filter::StartsWithIgnoreCase,
+                    )
+                };
+                assert!(
+                    matches!(
+                        Inputs::inner_read_with_suggestion("msg", None, completer(), |_| Err(
+                            InquireError::OperationCanceled
+                        )),
+                        Err(InquireError::OperationCanceled)
+                    ),
+                    "didn't relay cancel"
+                );
+                assert!(
+                    matches!(
+                        Inputs::inner_read_with_suggestion("msg", None, completer(), |_| Err(
+                            InquireError::OperationInterrupted
+                        )),
+                        Err(InquireError::OperationInterrupted)
+                    ),
+                    "didn't relay interrupt"
+                );
+                assert_eq!(
+                    "a",
+                    Inputs::inner_read_with_suggestion("msg", Some("a"), completer(), |text| {
+                        Ok(text.initial_value.unwrap_or_default().to_owned())
+                    })
+                    .unwrap(),
+                    "didn't pass prompt"
+                );
+            }
+        }
+
         #[allow(missing_docs)]
         pub mod autocompleter {
-            use std::fmt::Debug;
+            use std::{collections::VecDeque, fmt::Debug};
 
             use itertools::Itertools;
 
@@ -312,6 +982,41 @@ pub mod args {
                 #[allow(clippy::missing_errors_doc)]
                 fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error>;
 
+                /// Like [`Self::get_suggestions`], but returns at most `limit` suggestions.
+                ///
+                /// The default implementation truncates the result of [`Self::get_suggestions`],
+                /// implementors may override it to stop early.
+                #[allow(clippy::missing_errors_doc)]
+                fn get_suggestions_limited(
+                    &mut self,
+                    input: &str,
+                    limit: usize,
+                ) -> Result<Vec<String>, Error> {
+                    let mut suggestions = self.get_suggestions(input)?;
+                    suggestions.truncate(limit);
+                    Ok(suggestions)
+                }
+
+                /// Completes `input` to the longest common prefix of [`Self::get_suggestions`].
+                ///
+                /// Gives `None` when the prefix isn't longer than `input`,
+                /// implementors may delegate [`Self::get_completion`] to it.
+                #[allow(clippy::missing_errors_doc)]
+                fn complete_to_common_prefix(&mut self, input: &str) -> Result<Replacement, Error> {
+                    let suggestions = self.get_suggestions(input)?;
+                    let Some((first, rest)) = suggestions.split_first() else {
+                        return Ok(None);
+                    };
+                    let prefix_len = rest.iter().fold(first.len(), |len, other| {
+                        first[..len]
+                            .char_indices()
+                            .zip(other.chars())
+                            .find(|((_, a), b)| a != b)
+                            .map_or_else(|| len.min(other.len()), |((i, _), _)| i)
+                    });
+                    Ok((prefix_len > input.len()).then(|| first[..prefix_len].to_owned()))
+                }
+
                 /// Standalone autocompletion that can be implemented based solely on the user's
                 /// input.
                 ///
@@ -337,6 +1042,20 @@ pub mod args {
                     (**self).get_suggestions(input)
                 }
 
+                #[inline]
+                fn get_suggestions_limited(
+                    &mut self,
+                    input: &str,
+                    limit: usize,
+                ) -> Result<Vec<String>, Error> {
+                    (**self).get_suggestions_limited(input, limit)
+                }
+
+                #[inline]
+                fn complete_to_common_prefix(&mut self, input: &str) -> Result<Replacement, Error> {
+                    (**self).complete_to_common_prefix(input)
+                }
+
                 #[inline]
                 fn get_completion(
                     &mut self,
@@ -371,6 +1090,31 @@ pub mod args {
                     // Self { inner: self.inner }
                 }
             }
+            impl Autocomplete for BorrowCompleter {
+                fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error> {
+                    self.inner.get_suggestions(input)
+                }
+
+                fn get_suggestions_limited(
+                    &mut self,
+                    input: &str,
+                    limit: usize,
+                ) -> Result<Vec<String>, Error> {
+                    self.inner.get_suggestions_limited(input, limit)
+                }
+
+                fn complete_to_common_prefix(&mut self, input: &str) -> Result<Replacement, Error> {
+                    self.inner.complete_to_common_prefix(input)
+                }
+
+                fn get_completion(
+                    &mut self,
+                    input: &str,
+                    highlighted_suggestion: Option<String>,
+                ) -> Result<Replacement, Error> {
+                    self.inner.get_completion(input, highlighted_suggestion)
+                }
+            }
             impl inquire::Autocomplete for BorrowCompleter {
                 fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error> {
                     self.inner.get_suggestions(input)
@@ -411,15 +1155,91 @@ pub mod args {
                         metric,
                     )
                 }
+                /// replaces all suggestions with `data`
+                ///
+                /// the data is read on each call to [`Autocomplete::get_suggestions`], so changes are visible with the next query
+                pub fn set_data(&mut self, data: Vec<String>) {
+                    self.data = data;
+                }
+                /// adds `entry` to the suggestions
+                pub fn push_entry(&mut self, entry: String) {
+                    self.data.push(entry);
+                }
             }
             impl Autocomplete for VecCompleter {
                 fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error> {
+                    self.get_suggestions_limited(input, usize::MAX)
+                }
+
+                fn get_suggestions_limited(
+                    &mut self,
+                    input: &str,
+                    limit: usize,
+                ) -> Result<Vec<String>, Error> {
                     Ok(crate::str::filter::sort_with(
                         self.metric.as_ref(),
                         self.data.iter(),
                         input,
                         |it| it,
                     )
+                    .take(limit)
+                    .cloned()
+                    .collect_vec())
+                }
+
+                fn get_completion(
+                    &mut self,
+                    _input: &str,
+                    highlighted_suggestion: Option<String>,
+                ) -> Result<Replacement, Error> {
+                    Ok(highlighted_suggestion)
+                }
+            }
+
+            #[derive(Debug)]
+            /// suggests previously recorded entries, the most recent first on equal distance
+            pub struct HistoryCompleter {
+                history: VecDeque<String>,
+                capacity: usize,
+                metric: Box<dyn StrMetric + Send>,
+            }
+            impl HistoryCompleter {
+                /// creates a new empty history, that holds at most `capacity` entries
+                #[must_use]
+                pub fn new(capacity: usize, metric: impl StrMetric + Send + 'static) -> Self {
+                    Self {
+                        history: VecDeque::with_capacity(capacity),
+                        capacity,
+                        metric: Box::new(metric),
+                    }
+                }
+                /// records `entry` as the most recent entry
+                /// an equal older entry is removed and the oldest entries are evicted when the capacity is exceeded
+                pub fn record(&mut self, entry: String) {
+                    if let Some(index) = self.history.iter().position(|it| *it == entry) {
+                        self.history.remove(index);
+                    }
+                    self.history.push_front(entry);
+                    self.history.truncate(self.capacity);
+                }
+            }
+            impl Autocomplete for HistoryCompleter {
+                fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error> {
+                    self.get_suggestions_limited(input, usize::MAX)
+                }
+
+                fn get_suggestions_limited(
+                    &mut self,
+                    input: &str,
+                    limit: usize,
+                ) -> Result<Vec<String>, Error> {
+                    Ok(crate::str::filter::sort_with(
+                        self.metric.as_ref(),
+                        self.history.iter(),
+                        input,
+                        |it| it,
+                    )
+                    .take(limit)
                     .cloned()
                     .collect_vec())
                 }
@@ -432,12 +1252,178 @@ pub mod args {
                     Ok(highlighted_suggestion)
                 }
             }
+
+            #[cfg(test)]
+            mod tests {
+                use super::*;
+                use crate::str::filter::StartsWithIgnoreCase;
+
+                #[derive(Debug)]
+                struct AllCompleter(Vec<String>);
+                impl Autocomplete for AllCompleter {
+                    fn get_suggestions(&mut self, _input: &str) -> Result<Vec<String>, Error> {
+                        Ok(self.0.clone())
+                    }
+
+                    fn get_completion(
+                        &mut self,
+                        _input: &str,
+                        highlighted_suggestion: Option<String>,
+                    ) -> Result<Replacement, Error> {
+                        Ok(highlighted_suggestion)
+                    }
+                }
+
+                #[test]
+                fn default_limit() {
+                    let mut completer =
+                        AllCompleter(["a", "b", "c"].map(ToOwned::to_owned).to_vec());
+                    assert_eq!(
+                        vec!["a", "b"],
+                        completer.get_suggestions_limited("", 2).unwrap(),
+                        "failed to truncate"
+                    );
+                    assert_eq!(
+                        vec!["a", "b", "c"],
+                        completer.get_suggestions_limited("", 5).unwrap(),
+                        "failed with limit above len"
+                    );
+                }
+
+                #[test]
+                fn common_prefix() {
+                    let mut completer = AllCompleter(
+                        ["get_user", "get_users", "get_uuid"]
+                            .map(ToOwned::to_owned)
+                            .to_vec(),
+                    );
+                    assert_eq!(
+                        Some(String::from("get_u")),
+                        completer.complete_to_common_prefix("ge").unwrap(),
+                        "failed to complete to common prefix"
+                    );
+                    assert_eq!(
+                        None,
+                        completer.complete_to_common_prefix("get_u").unwrap(),
+                        "completed without extending input"
+                    );
+                    assert_eq!(
+                        None,
+                        AllCompleter(Vec::new())
+                            .complete_to_common_prefix("")
+                            .unwrap(),
+                        "completed without suggestions"
+                    );
+                }
+
+                #[derive(Debug)]
+                struct LimitedCompleter(AllCompleter);
+                impl Autocomplete for LimitedCompleter {
+                    fn get_suggestions(&mut self, input: &str) -> Result<Vec<String>, Error> {
+                        self.0.get_suggestions(input)
+                    }
+
+                    fn get_suggestions_limited(
+                        &mut self,
+                        _input: &str,
+                        limit: usize,
+                    ) -> Result<Vec<String>, Error> {
+                        Ok(vec![format!("limited to {limit}")])
+                    }
+
+                    fn get_completion(
+                        &mut self,
+                        input: &str,
+                        highlighted_suggestion: Option<String>,
+                    ) -> Result<Replacement, Error> {
+                        self.0.get_completion(input, highlighted_suggestion)
+                    }
+                }
+
+                #[test]
+                fn borrow_forwards_limit() {
+                    let mut completer =
+                        AllCompleter(["a", "b", "c"].map(ToOwned::to_owned).to_vec());
+                    {
+                        // SAFETY: `borrow` is dropped before `completer`
+                        let mut borrow = unsafe { BorrowCompleter::new(&mut completer) };
+                        assert_eq!(
+                            vec!["a", "b"],
+                            borrow.get_suggestions_limited("", 2).unwrap(),
+                            "failed to forward default truncation"
+                        );
+                    }
+
+                    let mut completer = LimitedCompleter(completer);
+                    {
+                        // SAFETY: `borrow` is dropped before `completer`
+                        let mut borrow = unsafe { BorrowCompleter::new(&mut completer) };
+                        assert_eq!(
+                            vec!["limited to 2"],
+                            borrow.get_suggestions_limited("", 2).unwrap(),
+                            "failed to forward override"
+                        );
+                    }
+                }
+
+                #[test]
+                fn vec_completer_limit() {
+                    let mut completer =
+                        VecCompleter::from_iter(["abc", "xyz", "ab", "a"], StartsWithIgnoreCase);
+                    assert_eq!(
+                        vec!["abc", "ab"],
+                        completer.get_suggestions_limited("ab", 2).unwrap(),
+                        "failed to limit sorted suggestions"
+                    );
+                }
+
+                #[test]
+                fn vec_completer_update() {
+                    let mut completer = VecCompleter::from_iter(["abc"], StartsWithIgnoreCase);
+                    let suggest = |completer: &mut dyn Autocomplete| {
+                        completer.get_suggestions_limited("ab", 2).unwrap()
+                    };
+                    assert_eq!(vec!["abc"], suggest(&mut completer), "failed initial data");
+                    completer.push_entry("ab".to_owned());
+                    assert_eq!(vec!["abc", "ab"], suggest(&mut completer), "failed to push");
+                    completer.set_data(vec!["xyz".to_owned(), "abd".to_owned()]);
+                    assert_eq!(vec!["abd", "xyz"], suggest(&mut completer), "failed to set");
+                }
+
+                #[test]
+                fn history_eviction() {
+                    let mut completer = HistoryCompleter::new(3, StartsWithIgnoreCase);
+                    for entry in ["a", "b", "c", "a", "d"] {
+                        completer.record(entry.to_owned());
+                    }
+                    assert_eq!(
+                        vec!["d", "a", "c"],
+                        completer.history.iter().collect_vec(),
+                        "failed to dedup or evict oldest"
+                    );
+                }
+
+                #[test]
+                fn history_suggestions() {
+                    let mut completer = HistoryCompleter::new(5, StartsWithIgnoreCase);
+                    assert!(completer.get_suggestions("ab").unwrap().is_empty());
+                    completer.record("abc".to_owned());
+                    completer.record("xyz".to_owned());
+                    completer.record("abd".to_owned());
+                    assert_eq!(
+                        vec!["abd", "abc", "xyz"],
+                        completer.get_suggestions("ab").unwrap(),
+                        "failed to rank matches by recency"
+                    );
+                }
+            }
         }
     }
 
     /// common debug utils
     pub mod debug {
         use clap::Args;
+        use std::sync::{Arc, Mutex, OnceLock, PoisonError};
 
         #[derive(Args, Debug, Clone, Copy)]
         #[group(required = false, multiple = false)]
@@ -473,6 +1459,56 @@ pub mod args {
 
                 builder.init();
             }
+            /// installs a logger, that stores all records, and returns a handle to them
+            ///
+            /// all calls share the same logger and buffer,
+            /// which keeps growing until it is [cleared](LogCapture::clear) or [taken](LogCapture::take)
+            ///
+            /// # Panics
+            /// panics when a different logger was already installed
+            pub fn init_capturing() -> LogCapture {
+                static LOGGER: OnceLock<CaptureLogger> = OnceLock::new();
+                let logger = LOGGER.get_or_init(|| CaptureLogger(LogCapture::default()));
+                if log::set_logger(logger).is_ok() {
+                    log::set_max_level(log::LevelFilter::Trace);
+                }
+                assert!(
+                    std::ptr::addr_eq(log::logger(), logger),
+                    "a different logger was already installed"
+                );
+                logger.0.clone()
+            }
+        }
+
+        /// a handle to the records captured by [`OutputLevel::init_capturing`]
+        #[derive(Debug, Clone, Default)]
+        pub struct LogCapture(Arc<Mutex<Vec<String>>>);
+        impl LogCapture {
+            /// returns the messages of all captured records
+            pub fn records(&self) -> Vec<String> {
+                self.lock().clone()
+            }
+            /// returns the messages of all captured records and removes them from the buffer
+            pub fn take(&self) -> Vec<String> {
+                std::mem::take(&mut *self.lock())
+            }
+            /// removes all captured records
+            pub fn clear(&self) {
+                self.lock().clear();
+            }
+            fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+                self.0.lock().unwrap_or_else(PoisonError::into_inner)
+            }
+        }
+        struct CaptureLogger(LogCapture);
+        impl log::Log for CaptureLogger {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+            fn log(&self, record: &log::Record) {
+                self.0.lock().push(record.args().to_string());
+            }
+            fn flush(&self) {}
         }
 
         impl From<OutputLevel> for log::Level {
@@ -490,5 +1526,40 @@ pub mod args {
                 }
             }
         }
+
+        #[cfg(test)]
+        mod tests {
+            use super::*;
+
+            #[test]
+            fn capturing() {
+                // other tests may log concurrently, so only look for this message
+                const MSG: &str = "captured by args::debug::tests::capturing";
+                let capture = OutputLevel::init_capturing();
+                log::info!("{MSG}");
+                assert!(
+                    capture.records().iter().any(|it| it == MSG),
+                    "didn't capture record"
+                );
+                assert!(
+                    Arc::ptr_eq(&capture.0, &OutputLevel::init_capturing().0),
+                    "didn't share buffer"
+                );
+                assert!(
+                    capture.take().iter().any(|it| it == MSG),
+                    "didn't take record"
+                );
+                assert!(
+                    !capture.records().iter().any(|it| it == MSG),
+                    "didn't remove taken record"
+                );
+                log::info!("{MSG}");
+                capture.clear();
+                assert!(
+                    !capture.records().iter().any(|it| it == MSG),
+                    "didn't clear record"
+                );
+            }
+        }
     }
 }
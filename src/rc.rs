@@ -2,19 +2,44 @@
 // SPDX-License-Identifier: MPL-2.0
 
 #![allow(missing_docs)]
-use std::{borrow::Borrow, ops::Deref, rc::Rc, sync::Arc};
+use std::{borrow::Borrow, ops::Deref, pin::Pin, rc::Rc, sync::Arc};
 
 pub trait Generic<T: ?Sized>: Clone + Deref + Borrow<T> + AsRef<T> + Unpin {
     fn new(value: T) -> Self
+    where
+        T: Sized;
+    /// creates a new pinned pointer, `value` can't be moved out of it afterwards
+    fn pin(value: T) -> Pin<Self>
     where
         T: Sized;
     fn into_inner(this: Self) -> Option<T>
+    where
+        T: Sized;
+    /// returns the inner value if `this` is the only strong reference, otherwise `this` is returned unchanged
+    #[allow(clippy::missing_errors_doc)]
+    fn try_unwrap(this: Self) -> Result<T, Self>
     where
         T: Sized;
     fn unwrap_or_clone(this: Self) -> T
     where
         T: Clone;
     fn get_mut(this: &mut Self) -> Option<&mut T>;
+    /// returns a pointer to the held value, usable for identity comparisons
+    fn as_ptr(this: &Self) -> *const T;
+    /// converts into an `Arc`, cloning the value if it is shared
+    fn into_arc(this: Self) -> Arc<T>
+    where
+        T: Clone,
+    {
+        Arc::new(Self::unwrap_or_clone(this))
+    }
+    /// converts into an `Rc`, cloning the value if it is shared
+    fn into_rc(this: Self) -> Rc<T>
+    where
+        T: Clone,
+    {
+        Rc::new(Self::unwrap_or_clone(this))
+    }
 }
 impl<T: ?Sized> Generic<T> for Rc<T> {
     fn new(value: T) -> Self
@@ -23,12 +48,24 @@ impl<T: ?Sized> Generic<T> for Rc<T> {
     {
         Self::new(value)
     }
+    fn pin(value: T) -> Pin<Self>
+    where
+        T: Sized,
+    {
+        Self::pin(value)
+    }
     fn into_inner(this: Self) -> Option<T>
     where
         T: Sized,
     {
         Self::try_unwrap(this).ok()
     }
+    fn try_unwrap(this: Self) -> Result<T, Self>
+    where
+        T: Sized,
+    {
+        Self::try_unwrap(this)
+    }
     fn unwrap_or_clone(this: Self) -> T
     where
         T: Clone,
@@ -38,6 +75,12 @@ impl<T: ?Sized> Generic<T> for Rc<T> {
     fn get_mut(this: &mut Self) -> Option<&mut T> {
         Self::get_mut(this)
     }
+    fn as_ptr(this: &Self) -> *const T {
+        Self::as_ptr(this)
+    }
+    fn into_rc(this: Self) -> Self {
+        this
+    }
 }
 impl<T: ?Sized> Generic<T> for Arc<T> {
     fn new(value: T) -> Self
@@ -46,12 +89,24 @@ impl<T: ?Sized> Generic<T> for Arc<T> {
     {
         Self::new(value)
     }
+    fn pin(value: T) -> Pin<Self>
+    where
+        T: Sized,
+    {
+        Self::pin(value)
+    }
     fn into_inner(this: Self) -> Option<T>
     where
         T: Sized,
     {
         Self::try_unwrap(this).ok()
     }
+    fn try_unwrap(this: Self) -> Result<T, Self>
+    where
+        T: Sized,
+    {
+        Self::try_unwrap(this)
+    }
     fn unwrap_or_clone(this: Self) -> T
     where
         T: Clone,
@@ -61,4 +116,86 @@ impl<T: ?Sized> Generic<T> for Arc<T> {
     fn get_mut(this: &mut Self) -> Option<&mut T> {
         Self::get_mut(this)
     }
+    fn as_ptr(this: &Self) -> *const T {
+        Self::as_ptr(this)
+    }
+    fn into_arc(this: Self) -> Self {
+        this
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn __test_as_ptr<G: Generic<u8>>() {
+        let a = G::new(1);
+        let b = a.clone();
+        let c = G::new(1);
+        assert_eq!(
+            G::as_ptr(&a),
+            G::as_ptr(&b),
+            "clones have differend pointers"
+        );
+        assert_ne!(G::as_ptr(&a), G::as_ptr(&c), "allocations share a pointer");
+    }
+    #[test]
+    fn as_ptr() {
+        __test_as_ptr::<Rc<_>>();
+        __test_as_ptr::<Arc<_>>();
+    }
+
+    fn __test_try_unwrap<G: Generic<u8> + std::fmt::Debug + PartialEq>() {
+        assert_eq!(Ok(1), G::try_unwrap(G::new(1)), "failed unique");
+
+        let a = G::new(1);
+        let b = a.clone();
+        let a = G::try_unwrap(a).expect_err("unwrapped shared pointer");
+        assert_eq!(G::as_ptr(&a), G::as_ptr(&b), "didn't return the pointer");
+        drop(b);
+        assert_eq!(Ok(1), G::try_unwrap(a), "failed after drop");
+    }
+    #[test]
+    fn try_unwrap() {
+        __test_try_unwrap::<Rc<_>>();
+        __test_try_unwrap::<Arc<_>>();
+    }
+
+    fn __test_pin<G: Generic<String> + Deref<Target = String>>() {
+        let pinned = G::pin(String::from("value"));
+        assert_eq!("value", pinned.as_str(), "failed to deref");
+    }
+    #[test]
+    fn pin() {
+        __test_pin::<Rc<_>>();
+        __test_pin::<Arc<_>>();
+    }
+
+    fn __build<G: Generic<String>>(value: &str) -> G {
+        G::new(value.to_owned())
+    }
+    #[test]
+    fn into_arc() {
+        let rc = __build::<Rc<_>>("value");
+        let shared = rc.clone();
+        let arc = Generic::into_arc(rc);
+        assert_eq!("value", arc.as_str(), "changed shared value");
+        assert_eq!(1, Rc::strong_count(&shared), "didn't release rc");
+
+        let arc = Generic::into_arc(__build::<Rc<_>>("value"));
+        assert_eq!("value", arc.as_str(), "changed unique value");
+
+        let arc = __build::<Arc<_>>("value");
+        let ptr = Arc::as_ptr(&arc);
+        assert_eq!(ptr, Arc::as_ptr(&Generic::into_arc(arc)), "reallocated arc");
+    }
+    #[test]
+    fn into_rc() {
+        let rc = Generic::into_rc(__build::<Arc<_>>("value"));
+        assert_eq!("value", rc.as_str(), "changed value");
+
+        let rc = __build::<Rc<_>>("value");
+        let ptr = Rc::as_ptr(&rc);
+        assert_eq!(ptr, Rc::as_ptr(&Generic::into_rc(rc)), "reallocated rc");
+    }
 }
@@ -7,6 +7,11 @@ use thiserror::Error;
 
 use crate::extensions::iter::CloneIteratorExt;
 
+/// the length in bytes of the first char, or 0 for empty strings
+fn first_char_len(word: &str) -> usize {
+    word.chars().next().map_or(0, char::len_utf8)
+}
+
 /// Different Cases a Word can be in
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WordCase {
@@ -19,6 +24,9 @@ pub enum WordCase {
     /// The first character is uppercase or doesn't have a case, the rest are lowercase or don't have a case.
     /// Lower/Upper case is defined by [`WordCase::Lower`] / [`WordCase::Upper`]
     Capitalized,
+    /// Every character has its case inverted, characters without a case are kept.
+    /// A word is never considered to be in this case, so it will always be converted
+    Toggle,
 }
 impl WordCase {
     #[inline]
@@ -27,25 +35,80 @@ impl WordCase {
             Self::Lower => word.chars().any(char::is_uppercase),
             Self::Upper => word.chars().any(char::is_lowercase),
             Self::Capitalized => {
-                !word.is_empty()
-                    && (Self::Upper.word_not_in_case(&word[..1])
-                        || Self::Lower.word_not_in_case(&word[1..]))
+                let (first, rest) = word.split_at(first_char_len(word));
+                Self::Upper.word_not_in_case(first) || Self::Lower.word_not_in_case(rest)
             }
+            Self::Toggle => true,
         }
     }
+    /// detects the case of `word`, checking for [`Self::Lower`], [`Self::Upper`] and then [`Self::Capitalized`]
+    ///
+    /// returns `None` for mixed case. Words without any cased characters are considered [`Self::Lower`]
+    pub fn detect(word: &str) -> Option<Self> {
+        Self::detect_common(&std::iter::once(word))
+    }
+    /// the first of [`Self::Lower`], [`Self::Upper`] and [`Self::Capitalized`] all `words` are in, or `None` for mixed case
+    fn detect_common<'s>(words: &(impl Iterator<Item = &'s str> + Clone)) -> Option<Self> {
+        [Self::Lower, Self::Upper, Self::Capitalized]
+            .into_iter()
+            .find(|case| !words.clone().any(|it| case.word_not_in_case(it)))
+    }
     #[momo::momo]
-    #[allow(clippy::needless_lifetimes)]
+    #[allow(clippy::needless_lifetimes, clippy::elidable_lifetime_names)]
     fn convert<'a>(self, word: impl Into<Cow<'a, str>>) -> Cow<'a, str> {
         if word.is_empty() {
             return word;
         }
+        Cow::Owned(if word.is_ascii() {
+            self.convert_ascii(word.into_owned())
+        } else {
+            self.convert_unicode(&word)
+        })
+    }
+    /// converts `word` in place without the unicode mappings, only valid for ascii words
+    fn convert_ascii(self, mut word: String) -> String {
         match self {
-            Self::Lower => Cow::Owned(word.to_lowercase()),
-            Self::Upper => Cow::Owned(word.to_uppercase()),
+            Self::Lower => word.make_ascii_lowercase(),
+            Self::Upper => word.make_ascii_uppercase(),
             Self::Capitalized => {
-                let mut new_word = word[..1].to_uppercase();
-                new_word.push_str(&word[1..].to_lowercase());
-                Cow::Owned(new_word)
+                word.make_ascii_lowercase();
+                word[..1].make_ascii_uppercase();
+            }
+            Self::Toggle => {
+                let mut bytes = word.into_bytes();
+                for byte in &mut bytes {
+                    if byte.is_ascii_uppercase() {
+                        byte.make_ascii_lowercase();
+                    } else {
+                        byte.make_ascii_uppercase();
+                    }
+                }
+                word = String::from_utf8(bytes)
+                    .unwrap_or_else(|_| unreachable!("toggling ascii keeps the bytes valid"));
+            }
+        }
+        word
+    }
+    fn convert_unicode(self, word: &str) -> String {
+        match self {
+            Self::Lower => word.to_lowercase(),
+            Self::Upper => word.to_uppercase(),
+            Self::Capitalized => {
+                let (first, rest) = word.split_at(first_char_len(word));
+                let mut new_word = first.to_uppercase();
+                new_word.push_str(&rest.to_lowercase());
+                new_word
+            }
+            Self::Toggle => {
+                let mut new_word = String::with_capacity(word.len());
+                for char in word.chars() {
+                    if char.is_uppercase() {
+                        new_word.extend(char.to_lowercase());
+                    } else {
+                        new_word.extend(char.to_uppercase());
+                    }
+                }
+                new_word
             }
         }
     }
@@ -126,6 +189,53 @@ impl Case {
         }
     }
 
+    /// detects the case of `s` by finding a single seperator of ' ', '-' or '_'
+    ///
+    /// # Errors
+    /// returns [`MixedSeperators`] when more than one kind of seperator is found
+    pub fn detect(s: &str) -> Result<Self, MixedSeperators> {
+        Ok(Self::detect_with_seperator(s, find_seperator(s)?))
+    }
+    /// detects the case of `data` split at `seperator` if `Some` or at capitalized letters if `None`
+    fn detect_with_seperator(data: &str, seperator: impl Into<Option<char>>) -> Self {
+        match seperator.into() {
+            Some(seperator) => Self::Other {
+                case: WordCase::detect_common(&data.split(seperator)),
+                seperator: Some(seperator),
+            },
+            None if data.is_empty() => Self::Lower,
+            None => {
+                let mut contains_lower = false;
+                let mut contains_upper = false;
+
+                let first = data
+                    .chars()
+                    .next()
+                    .unwrap_or_else(|| unreachable!("data can't be empty"));
+                let is_first_upper = first.is_uppercase();
+                if is_first_upper {
+                    contains_upper = true;
+                } else if first.is_lowercase() {
+                    contains_lower = true;
+                }
+
+                for char in data.chars() {
+                    contains_lower |= char.is_lowercase();
+                    contains_upper |= char.is_uppercase();
+                    if contains_lower && contains_upper {
+                        break; // nothing more can be gained by checking the rest
+                    }
+                }
+                match (is_first_upper, contains_lower, contains_upper) {
+                    (_, false | true, false) => Self::Lower,
+                    (_, false, true) => Self::Upper,
+                    (true, true, true) => Self::Pascal,
+                    (false, true, true) => Self::Camel,
+                }
+            }
+        }
+    }
+
     fn split(seperator: impl Into<Option<char>>, data: &str) -> Vec<Cow<'_, str>> {
         seperator.into().map_or_else(
             || Self::split_capitalized(data),
@@ -195,46 +305,32 @@ pub struct CapitalizedString<'a> {
 
 impl<'a> CapitalizedString<'a> {
     /// splits `data` at `seperator` if `Some` or at capitalized letters if `None`
+    ///
+    /// all other characters stay part of the words, even ones that could be a seperator like `'_'` or `'.'`.
+    /// empty words from leading, trailing or repeated seperators are kept, so that the original string can be restored.
+    /// Use [`Self::skip_empty_words`] to remove them
     pub fn new(data: &'a str, seperator: impl Into<Option<char>>) -> Self {
-        let case = match seperator.into() {
-            Some(seperator) => Case::Other {
-                case: None,
-                seperator: Some(seperator),
-            },
-            None if data.is_empty() => Case::Lower,
-            None => {
-                let mut contains_lower = false;
-                let mut contains_upper = false;
-
-                let first = data
-                    .chars()
-                    .next()
-                    .unwrap_or_else(|| unreachable!("data can't be empty"));
-                let is_first_upper = first.is_uppercase();
-                if is_first_upper {
-                    contains_upper = true;
-                } else if first.is_lowercase() {
-                    contains_lower = true;
-                };
-
-                for char in data.chars() {
-                    contains_lower |= char.is_lowercase();
-                    contains_upper |= char.is_uppercase();
-                    if contains_lower && contains_upper {
-                        break; // nothing more can be gained by checking the rest
-                    }
-                }
-                match (is_first_upper, contains_lower, contains_upper) {
-                    (_, false | true, false) => Case::Lower,
-                    (_, false, true) => Case::Upper,
-                    (true, true, true) => Case::Pascal,
-                    (false, true, true) => Case::Camel,
-                }
-            }
-        };
+        let case = Case::detect_with_seperator(data, seperator);
         let split = Case::split(case.seperator(), data);
         Self::from_words_unchecked(data, split, case)
     }
+    /// splits `data` at `seperator`, but treats a run of multiple seperators as a single one
+    ///
+    /// a leading or trailing run still creates an empty word.
+    /// `original_data` is only kept when no run was collapsed, as the output will only contain single seperators
+    pub fn new_normalized(data: &'a str, seperator: char) -> Self {
+        let mut this = Self::new(data, seperator);
+        let len = this.words.len();
+        let mut index = 0;
+        this.words.retain(|word| {
+            index += 1;
+            !word.is_empty() || index == 1 || index == len
+        });
+        if this.words.len() != len {
+            this.original_data = None;
+        }
+        this
+    }
     /// Creates a new `CapitaliedString` from `words` and `seperator`
     pub fn from_words<Iter>(words: Iter, seperator: impl Into<Option<char>>) -> Self
     where
@@ -296,6 +392,139 @@ impl<'a> CapitalizedString<'a> {
         self.case = case;
     }
 
+    /// the case `self` was detected or last converted to
+    ///
+    /// a `case` of `None` marks mixed case words, or words that where changed since the last conversion
+    #[inline]
+    pub const fn detected_case(&self) -> Case {
+        self.case
+    }
+
+    /// iterates over the current words without allocating
+    pub fn iter_words(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(std::convert::AsRef::as_ref)
+    }
+    /// checks if `self` and `other` hold the same words, regardless of thier seperator
+    ///
+    /// when `ignore_case` is true, the lowercase mappings of the words are compared
+    pub fn same_words(&self, other: &CapitalizedString<'_>, ignore_case: bool) -> bool {
+        self.words.len() == other.words.len()
+            && self.iter_words().zip(other.iter_words()).all(|(a, b)| {
+                if ignore_case {
+                    a.chars()
+                        .flat_map(char::to_lowercase)
+                        .eq(b.chars().flat_map(char::to_lowercase))
+                } else {
+                    a == b
+                }
+            })
+    }
+
+    /// A chainable variant of [`Self::remove_empty_words`]
+    #[inline]
+    pub fn skip_empty_words(mut self) -> Self {
+        self.remove_empty_words();
+        self
+    }
+    /// removes all empty words, like the ones created by leading or trailing seperators
+    pub fn remove_empty_words(&mut self) {
+        let len = self.words.len();
+        self.words.retain(|it| !it.is_empty());
+        if self.words.len() != len {
+            self.original_data = None;
+        }
+    }
+
+    /// appends `word` to the end of the words
+    /// the new word will be converted with the next call to [`Self::change_case`]
+    pub fn push_word(&mut self, word: impl Into<Cow<'a, str>>) {
+        self.words_changed();
+        self.words.push(word.into());
+    }
+    /// inserts `word` at position `index`
+    /// the new word will be converted with the next call to [`Self::change_case`]
+    ///
+    /// # Panics
+    /// Panics if `index > len`
+    pub fn insert_word(&mut self, index: usize, word: impl Into<Cow<'a, str>>) {
+        self.words_changed();
+        self.words.insert(index, word.into());
+    }
+    /// removes and returns the word at position `index`
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds
+    pub fn remove_word(&mut self, index: usize) -> Cow<'a, str> {
+        self.words_changed();
+        self.words.remove(index)
+    }
+    /// reverses the order of the words
+    /// the words will be converted with the next call to [`Self::change_case`]
+    pub fn reverse_words(&mut self) {
+        self.words_changed();
+        self.words.reverse();
+    }
+    /// rotates the words `n` places to the left, or to the right if `n` is negative
+    /// the words will be converted with the next call to [`Self::change_case`]
+    pub fn rotate_words(&mut self, n: isize) {
+        self.words_changed();
+        if self.words.is_empty() {
+            return;
+        }
+        let places = n.unsigned_abs() % self.words.len();
+        if n.is_negative() {
+            self.words.rotate_right(places);
+        } else {
+            self.words.rotate_left(places);
+        }
+    }
+    /// replaces each word with the output of `f`, which gets the index and the current word
+    ///
+    /// words for which `f` returns an equal string are kept as they are
+    pub fn map_words<F>(&mut self, mut f: F)
+    where
+        F: for<'w> FnMut(usize, &'w str) -> Cow<'w, str>,
+    {
+        let mut has_changed = false;
+        for (index, word) in self.words.iter_mut().enumerate() {
+            let new = f(index, word);
+            if new == word.as_ref() {
+                continue;
+            }
+            *word = Cow::Owned(new.into_owned());
+            has_changed = true;
+        }
+        if has_changed {
+            self.words_changed();
+        }
+    }
+    /// forgets the original data and the case of the words, as they may not be correct anymore
+    const fn words_changed(&mut self) {
+        self.original_data = None;
+        self.case = Case::Other {
+            case: None,
+            seperator: self.case.seperator(),
+        };
+    }
+
+    /// clears `buf` and writes the joined words into it, reusing its capacity
+    pub fn write_into(&self, buf: &mut String) {
+        buf.clear();
+        if let Some(data) = self.original_data {
+            buf.push_str(data);
+            return;
+        }
+        let seperator = self.case.seperator();
+        for (i, word) in self.words.iter().enumerate() {
+            if i > 0 {
+                if let Some(sep) = seperator {
+                    buf.push(sep);
+                }
+            }
+            buf.push_str(word);
+        }
+    }
+
     /// Copys all borrowed data to become an owned type
     /// sadly can't be expressed by [`alloc::borrow::ToOwned`]
     pub fn into_owned(self) -> CapitalizedString<'static> {
@@ -322,6 +551,7 @@ impl<'a> From<&CapitalizedString<'a>> for Cow<'a, str> {
     }
 }
 #[allow(clippy::to_string_trait_impl)]
+#[allow(clippy::elidable_lifetime_names)]
 impl<'a> ToString for CapitalizedString<'a> {
     fn to_string(&self) -> String {
         Cow::from(self).into_owned()
@@ -336,17 +566,20 @@ impl<'a> TryFrom<&'a str> for CapitalizedString<'a> {
     type Error = MixedSeperators;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        const DELIMITERS: [char; 3] = [' ', '-', '_'];
-        let candidates = value
-            .chars()
-            .filter(|char| DELIMITERS.contains(char))
-            .collect::<HashSet<_>>();
-        let seperator = match candidates.len() {
-            0 => None,
-            1 => Some(candidates.into_iter().next().unwrap()),
-            _ => return Err(MixedSeperators(candidates)),
-        };
-        Ok(CapitalizedString::new(value, seperator))
+        Ok(CapitalizedString::new(value, find_seperator(value)?))
+    }
+}
+/// finds the only one of ' ', '-' and '_' in `value`
+fn find_seperator(value: &str) -> Result<Option<char>, MixedSeperators> {
+    const DELIMITERS: [char; 3] = [' ', '-', '_'];
+    let candidates = value
+        .chars()
+        .filter(|char| DELIMITERS.contains(char))
+        .collect::<HashSet<_>>();
+    match candidates.len() {
+        0 => Ok(None),
+        1 => Ok(candidates.into_iter().next()),
+        _ => Err(MixedSeperators(candidates)),
     }
 }
 
@@ -354,6 +587,41 @@ impl<'a> TryFrom<&'a str> for CapitalizedString<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn detect_single_word() {
+        assert_eq!(
+            Some(WordCase::Upper),
+            WordCase::detect("HELLO"),
+            "failed upper"
+        );
+        assert_eq!(
+            Some(WordCase::Lower),
+            WordCase::detect("hello"),
+            "failed lower"
+        );
+        assert_eq!(
+            Some(WordCase::Capitalized),
+            WordCase::detect("Hello"),
+            "failed capitalized"
+        );
+        assert_eq!(None, WordCase::detect("hELLo"), "failed mixed");
+        assert_eq!(
+            Some(WordCase::Lower),
+            WordCase::detect("42"),
+            "failed caseless"
+        );
+        assert_eq!(
+            Some(WordCase::Upper),
+            WordCase::detect("A"),
+            "failed single letter"
+        );
+        assert_eq!(
+            Some(WordCase::Capitalized),
+            WordCase::detect("\u{c9}mile"),
+            "failed multibyte first char"
+        );
+    }
+
     #[test]
     fn format_correctly() {
         fn __test_to_string(data: &str, words: Vec<&str>, case: Case) {
@@ -449,6 +717,85 @@ mod tests {
         assert_eq!("some data", data.to_string());
     }
 
+    #[test]
+    fn toggle() {
+        assert_eq!("hELLO", WordCase::Toggle.convert("Hello"));
+        assert_eq!("1-a-B", WordCase::Toggle.convert("1-A-b"));
+
+        let mut data = CapitalizedString::new("Hello World", ' ');
+        data.change_case(Case::new(WordCase::Toggle, '_'));
+        assert_eq!("hELLO_wORLD", data.to_string());
+        data.change_case(Case::new(WordCase::Toggle, ' '));
+        assert_eq!("Hello World", data.to_string());
+    }
+
+    #[test]
+    fn iter_words() {
+        let data = CapitalizedString::new("someCamelCase", None);
+        assert_eq!(
+            vec!["some", "Camel", "Case"],
+            data.iter_words().collect_vec()
+        );
+    }
+
+    #[test]
+    fn empty_words() {
+        let data = CapitalizedString::new("_a_b_", '_');
+        assert_eq!(vec!["", "a", "b", ""], data.words);
+        assert_eq!(
+            "_a_b_",
+            data.clone().into_case(Case::Snake).to_string(),
+            "failed to keep empty words"
+        );
+        assert_eq!(
+            "a_b",
+            data.skip_empty_words().into_case(Case::Snake).to_string(),
+            "failed to skip empty words"
+        );
+
+        let data = CapitalizedString::new("a_b", '_').skip_empty_words();
+        assert_eq!(Some("a_b"), data.original_data, "dropped original_data");
+    }
+
+    #[test]
+    fn normalized() {
+        let data = CapitalizedString::new_normalized("too   many   spaces", ' ');
+        assert_eq!(vec!["too", "many", "spaces"], data.words);
+        assert_eq!(
+            None, data.original_data,
+            "kept original_data after collapsing"
+        );
+        assert_eq!("too many spaces", data.to_string());
+
+        let data = CapitalizedString::new_normalized("__a__b_", '_');
+        assert_eq!(
+            vec!["", "a", "b", ""],
+            data.words,
+            "failed with leading run"
+        );
+        assert_eq!("_a_b_", data.to_string());
+
+        let orig = "no extra spaces";
+        let data = CapitalizedString::new_normalized(orig, ' ');
+        assert_eq!(Some(orig), data.original_data, "dropped original_data");
+    }
+
+    #[test]
+    fn mutate_words() {
+        let mut data = CapitalizedString::new("user", None);
+        data.push_word("id");
+        data.change_case(Case::Pascal);
+        assert_eq!("UserId", data.to_string());
+
+        data.insert_word(0, "the");
+        data.change_case(Case::Pascal);
+        assert_eq!("TheUserId", data.to_string());
+
+        assert_eq!("User", data.remove_word(1));
+        data.change_case(Case::Snake);
+        assert_eq!("the_id", data.to_string());
+    }
+
     #[test]
     fn convert_no_extra_allocation() {
         let orig = "datawithoutseperator!";
@@ -476,4 +823,188 @@ mod tests {
         data.change_case(Case::Pascal);
         assert_eq!(Some(orig), data.original_data);
     }
+
+    #[test]
+    fn detect_word_case() {
+        fn __test(data: &str, case: Case) {
+            assert_eq!(
+                case,
+                CapitalizedString::try_from(data).unwrap().detected_case(),
+                "failed for {data:?}"
+            );
+        }
+        __test("SCREAMING_SNAKE_CASE", Case::ScreamingSnake);
+        __test("snake_case", Case::Snake);
+        __test("kebab-case", Case::Kebab);
+        __test("lower words", Case::Lower);
+        __test("UPPER WORDS", Case::Upper);
+        __test("_LEADING_1", Case::ScreamingSnake);
+        __test("Capitalized-Words", Case::new(WordCase::Capitalized, '-'));
+        __test("\u{c9}mile_Zola", Case::new(WordCase::Capitalized, '_'));
+        __test(
+            "mixed_Case",
+            Case::Other {
+                case: None,
+                seperator: Some('_'),
+            },
+        );
+    }
+
+    #[test]
+    fn map_words() {
+        let mut data = CapitalizedString::new("some_words_here", '_');
+        data.map_words(|i, word| {
+            if i == 0 {
+                Cow::Owned(word.to_uppercase())
+            } else {
+                Cow::Borrowed(word)
+            }
+        });
+        assert_eq!("SOME_words_here", data.to_string());
+        assert!(
+            matches!(data.words[1], Cow::Borrowed(_)),
+            "unchanged word was copied"
+        );
+
+        let mut data = CapitalizedString::new("some_words_here", '_');
+        data.map_words(|_, word| Cow::Owned(word.to_owned()));
+        assert_eq!(
+            Some("some_words_here"),
+            data.original_data,
+            "dropped original_data without change"
+        );
+    }
+
+    #[test]
+    fn reorder_words() {
+        let mut data = CapitalizedString::from_words(["get", "user", "id"], None);
+        data.reverse_words();
+        assert_eq!("idUserGet", data.into_case(Case::Camel).to_string());
+
+        let mut data = CapitalizedString::new("getUserId", None);
+        data.rotate_words(1);
+        assert_eq!("userIdGet", data.clone().into_case(Case::Camel).to_string());
+        data.rotate_words(-2);
+        assert_eq!(
+            "id_get_user",
+            data.clone().into_case(Case::Snake).to_string()
+        );
+        data.rotate_words(3);
+        assert_eq!(
+            "id_get_user",
+            data.into_case(Case::Snake).to_string(),
+            "failed full rotation"
+        );
+    }
+
+    #[test]
+    fn detect_case() {
+        assert_eq!(Ok(Case::Snake), Case::detect("snake_case"), "failed snake");
+        assert_eq!(Ok(Case::Kebab), Case::detect("kebab-case"), "failed kebab");
+        assert_eq!(Ok(Case::Camel), Case::detect("camelCase"), "failed camel");
+        assert_eq!(
+            Ok(Case::Pascal),
+            Case::detect("PascalCase"),
+            "failed pascal"
+        );
+        assert_eq!(
+            Ok(Case::ScreamingSnake),
+            Case::detect("SCREAMING_SNAKE"),
+            "failed screaming snake"
+        );
+        assert_eq!(
+            Err(MixedSeperators(HashSet::from(['_', '-']))),
+            Case::detect("mixed_seperator-case"),
+            "failed mixed"
+        );
+    }
+
+    #[test]
+    fn write_into() {
+        let mut buf = String::new();
+        CapitalizedString::new_into("get_user_name", Case::Pascal)
+            .unwrap()
+            .write_into(&mut buf);
+        assert_eq!("GetUserName", buf, "failed changed words");
+        let (ptr, capacity) = (buf.as_ptr(), buf.capacity());
+
+        CapitalizedString::new_into("getUser", Case::Kebab)
+            .unwrap()
+            .write_into(&mut buf);
+        assert_eq!("get-user", buf, "failed with seperator");
+        CapitalizedString::new("user_id", '_').write_into(&mut buf);
+        assert_eq!("user_id", buf, "failed original data");
+
+        assert_eq!(ptr, buf.as_ptr(), "reallocated buffer");
+        assert_eq!(capacity, buf.capacity(), "changed capacity");
+    }
+
+    #[test]
+    fn keep_punctuation() {
+        let data = CapitalizedString::try_from("a-b.c").unwrap();
+        assert_eq!(Case::Kebab, data.detected_case(), "failed to detect kebab");
+        assert!(data.iter_words().eq(["a", "b.c"]), "split at non seperator");
+        assert!(
+            CapitalizedString::new("snake_part-kebab", '-')
+                .iter_words()
+                .eq(["snake_part", "kebab"]),
+            "split at other seperator"
+        );
+
+        let pascal = CapitalizedString::new_into("v1.2.3-beta", Case::Pascal)
+            .unwrap()
+            .to_string();
+        assert_eq!("V1.2.3Beta", pascal, "failed to convert version");
+        assert!(
+            CapitalizedString::new(&pascal, None)
+                .iter_words()
+                .eq(["V1.2.3", "Beta"]),
+            "split version at punctuation"
+        );
+    }
+
+    #[test]
+    fn same_words() {
+        let snake = CapitalizedString::new("get_user_id", '_');
+        let camel = CapitalizedString::new("getUserId", None);
+        assert!(snake.same_words(&camel, true), "failed ignoring case");
+        assert!(!snake.same_words(&camel, false), "ignored case");
+        assert!(
+            snake.same_words(&CapitalizedString::new("get-user-id", '-'), false),
+            "failed with other seperator"
+        );
+        assert!(
+            !snake.same_words(&CapitalizedString::new("getUser", None), true),
+            "matched fewer words"
+        );
+    }
+
+    #[test]
+    fn ascii_fast_path() {
+        let cases = [
+            WordCase::Lower,
+            WordCase::Upper,
+            WordCase::Capitalized,
+            WordCase::Toggle,
+        ];
+        for word in ["hello", "WORLD", "mIxEd", "v1.2-x", "a"] {
+            for case in cases {
+                assert_eq!(
+                    case.convert_unicode(word),
+                    case.convert(word),
+                    "differend result for {word:?} in {case:?}"
+                );
+            }
+        }
+        assert_eq!(
+            "\u{c9}CLAIR",
+            WordCase::Upper.convert("\u{e9}clair"),
+            "failed non ascii"
+        );
+        assert_eq!(
+            "\u{c9}clair",
+            WordCase::Capitalized.convert("\u{e9}CLAIR"),
+            "failed non ascii capitalized"
+        );
+    }
 }
@@ -1,9 +1,9 @@
 // SPDX-FileCopyrightText: 2024 Nils Jochem
 // SPDX-License-Identifier: MPL-2.0
 
-use std::fmt::Debug;
+use std::{borrow::Cow, collections::HashSet, fmt::Debug, ops::Range};
 
-use crate::extensions::iter::IteratorExt;
+use crate::{extensions::iter::IteratorExt, str::CaseSensitivity};
 use itertools::Itertools;
 
 #[allow(missing_docs)]
@@ -12,6 +12,35 @@ pub trait StrFilter: Debug {
     /// returns true if `input` matches the `option`
     fn filter(&self, option: &str, input: &str) -> bool;
 }
+/// combinators for [`StrFilter`]s
+#[allow(clippy::module_name_repetitions)]
+pub trait StrFilterExt: StrFilter + Sized {
+    /// matches only when both `self` and `other` match
+    fn and<O: StrFilter>(self, other: O) -> And<Self, O> {
+        And(self, other)
+    }
+    /// matches when either `self` or `other` matches
+    fn or<O: StrFilter>(self, other: O) -> Or<Self, O> {
+        Or(self, other)
+    }
+}
+impl<F: StrFilter> StrFilterExt for F {}
+#[derive(Debug, Clone, Copy)]
+/// matches only when both filters match, see [`StrFilterExt::and`]
+pub struct And<A, B>(pub A, pub B);
+impl<A: StrFilter, B: StrFilter> StrFilter for And<A, B> {
+    fn filter(&self, option: &str, input: &str) -> bool {
+        self.0.filter(option, input) && self.1.filter(option, input)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+/// matches when either filter matches, see [`StrFilterExt::or`]
+pub struct Or<A, B>(pub A, pub B);
+impl<A: StrFilter, B: StrFilter> StrFilter for Or<A, B> {
+    fn filter(&self, option: &str, input: &str) -> bool {
+        self.0.filter(option, input) || self.1.filter(option, input)
+    }
+}
 #[allow(missing_docs)]
 pub trait StrMetric: Debug {
     /// the relative distance between two words between 0 and 1.
@@ -51,31 +80,252 @@ where
         }) // sort 0->1->NaN
         .map(|(it, _)| it)
 }
+/// collects `stream` and sorts its elements like [`sort_with`]
+#[cfg(feature = "fut_iter")]
+#[allow(clippy::future_not_send)] // the future is Send, when the stream is
+pub async fn sort_stream<S, M, F>(metric: &M, stream: S, input: &str, get_str: F) -> Vec<S::Item>
+where
+    S: futures::Stream,
+    F: FnMut(&S::Item) -> &str,
+    M: StrMetric + ?Sized,
+{
+    use futures::StreamExt;
+    let items = stream.collect::<Vec<_>>().await;
+    sort_with(metric, items, input, get_str).collect()
+}
 #[derive(Debug, Clone, Copy)]
 /// filters a string by checking if the search term is a prefix
+///
+/// compares the lowercase mapping of each char and doesn't allocate.
+/// Context dependent mappings like a final sigma are not respected, unlike [`str::to_lowercase`]
 pub struct StartsWithIgnoreCase;
 impl StrFilter for StartsWithIgnoreCase {
     fn filter(&self, option: &str, input: &str) -> bool {
-        option.to_lowercase().starts_with(&input.to_lowercase())
+        if option.is_ascii() && input.is_ascii() {
+            return starts_with_ignore_ascii(option, input);
+        }
+        starts_with_mapped(option, input, char::to_lowercase)
+    }
+}
+fn starts_with_ignore_ascii(option: &str, input: &str) -> bool {
+    option.len() >= input.len()
+        && option
+            .bytes()
+            .zip(input.bytes())
+            .all(|(a, b)| a.eq_ignore_ascii_case(&b))
+}
+fn starts_with_mapped<I: Iterator<Item = char>>(
+    option: &str,
+    input: &str,
+    map: impl Fn(char) -> I,
+) -> bool {
+    let mut option = option.chars().flat_map(&map);
+    input
+        .chars()
+        .flat_map(&map)
+        .all(|b| option.next().is_some_and(|a| a == b))
+}
+#[derive(Debug, Clone, Copy)]
+/// filters a string by checking if the search term is a prefix, respecting `case`
+pub struct StartsWith {
+    /// how the case of both strings is compared
+    pub case: CaseSensitivity,
+}
+impl StrFilter for StartsWith {
+    fn filter(&self, option: &str, input: &str) -> bool {
+        match self.case {
+            CaseSensitivity::Sensitive => option.starts_with(input),
+            CaseSensitivity::Ascii => {
+                let mut option = option.chars();
+                input
+                    .chars()
+                    .all(|b| option.next().is_some_and(|a| a.eq_ignore_ascii_case(&b)))
+            }
+            #[cfg(feature = "unicode")]
+            CaseSensitivity::Unicode if option.is_ascii() && input.is_ascii() => {
+                starts_with_ignore_ascii(option, input)
+            }
+            #[cfg(feature = "unicode")]
+            CaseSensitivity::Unicode => starts_with_mapped(option, input, CaseSensitivity::fold),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// filters a string by checking if all chars of the search term appear in order, respecting `case`
+pub struct Subsequence {
+    /// how the case of both strings is compared
+    pub case: CaseSensitivity,
+}
+impl StrFilter for Subsequence {
+    fn filter(&self, option: &str, input: &str) -> bool {
+        self.match_ranges(option, input).is_some()
+    }
+}
+impl Subsequence {
+    /// returns the byte ranges of the chars in `option`, that matched `input`, or `None` if it doesn't match
+    ///
+    /// a contiguous match is preferred, otherwise each char is matched as early as possible.
+    /// Adjacent matched chars are merged into a single range
+    pub fn match_ranges(&self, option: &str, input: &str) -> Option<Vec<Range<usize>>> {
+        if input.is_empty() {
+            return Some(Vec::new());
+        }
+        if let Some(range) = self.find_contiguous(option, input) {
+            return Some(vec![range]);
+        }
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut option = option.char_indices();
+        for b in input.chars() {
+            let (start, a) = option.find(|&(_, a)| self.case.eq_char(a, b))?;
+            let end = start + a.len_utf8();
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+        Some(ranges)
+    }
+    fn find_contiguous(self, option: &str, input: &str) -> Option<Range<usize>> {
+        option.char_indices().find_map(|(start, _)| {
+            let mut option = option[start..].char_indices();
+            let mut end = start;
+            for b in input.chars() {
+                let (i, a) = option.next()?;
+                if !self.case.eq_char(a, b) {
+                    return None;
+                }
+                end = start + i + a.len_utf8();
+            }
+            Some(start..end)
+        })
     }
 }
 
 #[derive(Debug, Clone, Copy)]
 /// an implementation of Levenshteins Algorithm
 pub struct Levenshtein {
-    ignore_case: bool,
+    case: CaseSensitivity,
+    insert_cost: usize,
+    delete_cost: usize,
+    substitute_cost: usize,
 }
 impl StrMetric for Levenshtein {
     fn distance(&self, option: &str, input: &str) -> f64 {
-        let lev_distance = self.dynamic_distance(option.chars(), &input.chars().collect_vec());
-        let max = option.len().max(input.len());
+        let lev_distance = self.edit_distance(option, input);
+        let max = option.len().max(input.len()) * self.max_cost();
         lev_distance as f64 / max as f64
     }
 }
 impl Levenshtein {
-    #[allow(missing_docs)]
+    /// creates a new metric, that ignores the ascii case if `ignore_case` is true
     pub const fn new(ignore_case: bool) -> Self {
-        Self { ignore_case }
+        Self::with_case(if ignore_case {
+            CaseSensitivity::Ascii
+        } else {
+            CaseSensitivity::Sensitive
+        })
+    }
+    /// creates a new metric, that compares chars with respect to `case`
+    pub const fn with_case(case: CaseSensitivity) -> Self {
+        Self {
+            case,
+            insert_cost: 1,
+            delete_cost: 1,
+            substitute_cost: 1,
+        }
+    }
+    /// creates a new metric like [`Self::new`], but with a cost for each kind of edit
+    ///
+    /// # Panics
+    /// panics if any cost is zero
+    pub const fn with_costs(
+        ignore_case: bool,
+        insert: usize,
+        delete: usize,
+        substitute: usize,
+    ) -> Self {
+        assert!(
+            insert > 0 && delete > 0 && substitute > 0,
+            "costs need to be positive"
+        );
+        let mut this = Self::new(ignore_case);
+        this.insert_cost = insert;
+        this.delete_cost = delete;
+        this.substitute_cost = substitute;
+        this
+    }
+    const fn max_cost(self) -> usize {
+        let max = if self.insert_cost > self.delete_cost {
+            self.insert_cost
+        } else {
+            self.delete_cost
+        };
+        if max > self.substitute_cost {
+            max
+        } else {
+            self.substitute_cost
+        }
+    }
+    /// creates a new metric, that is boosted by `bonus` for a common prefix
+    ///
+    /// this is the recommended default for [`crate::args::input::autocompleter::VecCompleter`]
+    pub const fn with_prefix_boost(ignore_case: bool, bonus: f64) -> SameStartBoost<Self> {
+        let this = Self::new(ignore_case);
+        SameStartBoost {
+            ignore_case,
+            same_start_bonus: bonus,
+            other: this,
+        }
+    }
+    /// returns the summed cost of the single character edits needed to change `a` into `b`
+    pub fn edit_distance(&self, a: &str, b: &str) -> usize {
+        self.dynamic_distance(a.chars(), &b.chars().collect_vec())
+    }
+    /// like [`Self::edit_distance`], but returns `None` as soon as the distance is known to be greater than `max`
+    ///
+    /// only cells at most `max` away from the diagonal are calculated
+    pub fn bounded_distance(&self, a: &str, b: &str, max: usize) -> Option<usize> {
+        let a = a.chars().collect_vec();
+        let b = b.chars().collect_vec();
+        if a.len().abs_diff(b.len()) > max {
+            return None;
+        }
+        // any value above max is equivalent, so all distances are saturated at this
+        let out_of_band = max + 1;
+
+        // same layout as in `dynamic_distance`, but all cells outside of the band hold `out_of_band`
+        // the band stays valid, as every edit costs at least 1
+        let mut v0 = (0..=b.len())
+            .map(|j| (j * self.insert_cost).min(out_of_band))
+            .collect_vec();
+        let mut v1 = vec![out_of_band; b.len() + 1];
+        for (i, &a_char) in a.iter().lzip(1usize..) {
+            let start = i.saturating_sub(max).max(1);
+            let end = (i + max).min(b.len());
+
+            v1[0] = (i * self.delete_cost).min(out_of_band);
+            if start > 1 {
+                v1[start - 1] = out_of_band;
+            }
+            for j in start..=end {
+                let substitution_cost = v0[j - 1] + self.substitution(a_char, b[j - 1]);
+                let deletion_cost = v0[j] + self.delete_cost;
+                let insertion_cost = v1[j - 1] + self.insert_cost;
+                v1[j] = substitution_cost
+                    .min(deletion_cost)
+                    .min(insertion_cost)
+                    .min(out_of_band);
+            }
+            if end < b.len() {
+                v1[end + 1] = out_of_band;
+            }
+            if v1[start - 1..=end].iter().all(|&it| it > max) {
+                return None; // the distance can only grow from here
+            }
+            std::mem::swap(&mut v0, &mut v1);
+        }
+        Some(v0[b.len()]).filter(|&it| it <= max)
     }
     #[allow(dead_code)]
     fn recursive_distance(self, a: &[char], b: &[char]) -> usize {
@@ -83,7 +333,7 @@ impl Levenshtein {
             b.len()
         } else if b.is_empty() {
             a.len()
-        } else if crate::str::compare_char(a[0], b[0], self.ignore_case) {
+        } else if self.case.eq_char(a[0], b[0]) {
             self.recursive_distance(&a[1..], &b[1..])
         } else {
             let s1 = self.recursive_distance(&a[1..], b);
@@ -97,8 +347,8 @@ impl Levenshtein {
 
         // initialize v0 (the previous row of distances)
         // this row is A[0][i]: edit distance from an empty s to t;
-        // that distance is the number of characters to append to  s to make t.
-        let mut v0 = (0..=n).collect_vec();
+        // that distance is the cost of the characters to append to  s to make t.
+        let mut v0 = (0..=n).map(|j| j * self.insert_cost).collect_vec();
         // v1 may as well be uninit
         let mut v1 = vec![0; n + 1];
 
@@ -107,21 +357,15 @@ impl Levenshtein {
 
             // first element of v1 is A[i][0]
             // edit distance is delete (i) chars from s to match empty t
-            v1[0] = i;
+            v1[0] = i * self.delete_cost;
 
             // use formula to fill in the rest of the row
             for (j, &t_char) in t.iter().enumerate() {
                 // calculating costs for A[i][j + 1]
-                let (substitution_cost, overflowing) = v0[j].overflowing_sub(
-                    crate::str::compare_char(s_char, t_char, self.ignore_case) as usize,
-                );
-                v1[j + 1] = if overflowing {
-                    0
-                } else {
-                    let deletion_cost = v0[j + 1];
-                    let insertion_cost = v1[j];
-                    substitution_cost.min(insertion_cost).min(deletion_cost) + 1
-                };
+                let substitution_cost = v0[j] + self.substitution(s_char, t_char);
+                let deletion_cost = v0[j + 1] + self.delete_cost;
+                let insertion_cost = v1[j] + self.insert_cost;
+                v1[j + 1] = substitution_cost.min(insertion_cost).min(deletion_cost);
             }
             // copy v1 (current row) to v0 (previous row) for next iteration
             // since data in v1 is always invalidated, a swap without copy could be more efficient
@@ -130,36 +374,159 @@ impl Levenshtein {
         // after the last swap, the results of v1 are now in v0
         v0[n]
     }
+    fn substitution(self, a: char, b: char) -> usize {
+        if self.case.eq_char(a, b) {
+            0
+        } else {
+            self.substitute_cost
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 /// applies a multiplier realative to the maximal common prefix length
-pub struct SameStartBoost<O> {
+///
+/// `C` is a `bool` ignoring the ascii case like [`compare_char`](crate::str::compare_char), or any [`CaseSensitivity`]
+pub struct SameStartBoost<O, C = bool> {
     /// should the case be ignored, when calculaten the maximal common prefix
-    pub ignore_case: bool,
+    pub ignore_case: C,
     /// the base boost to be applied
     pub same_start_bonus: f64,
     /// the original metric
     pub other: O,
 }
-impl<O: StrMetric> StrMetric for SameStartBoost<O> {
+impl<O> SameStartBoost<O, CaseSensitivity> {
+    /// creates a new boost, that compares the prefix with respect to `case`
+    pub const fn with_case(case: CaseSensitivity, same_start_bonus: f64, other: O) -> Self {
+        Self {
+            ignore_case: case,
+            same_start_bonus,
+            other,
+        }
+    }
+}
+impl<O: StrMetric, C: Into<CaseSensitivity> + Copy + Debug> StrMetric for SameStartBoost<O, C> {
     fn distance(&self, option: &str, input: &str) -> f64 {
+        let case: CaseSensitivity = self.ignore_case.into();
         let distance = self.other.distance(option, input);
         let max = option.len().max(input.len());
         let prefix_len = option
             .chars()
             .zip(input.chars())
-            .take_while(|(a, b)| crate::str::compare_char(*a, *b, self.ignore_case))
+            .take_while(|(a, b)| case.eq_char(*a, *b))
             .count();
         let prefix_factor = prefix_len as f64 / max as f64;
         distance * (prefix_factor.mul_add(-self.same_start_bonus, 1.0))
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+/// compares the sets of whitespace seperated words, ignoring thier order and repetitions
+///
+/// the distance is `1 - |intersection| / |union|`
+pub struct TokenSetRatio {
+    /// how the case of the words is compared
+    pub case: CaseSensitivity,
+}
+impl StrMetric for TokenSetRatio {
+    fn distance(&self, option: &str, input: &str) -> f64 {
+        let (option, input) = (self.fold_case(option), self.fold_case(input));
+        let option = option.split_whitespace().collect::<HashSet<_>>();
+        let input = input.split_whitespace().collect::<HashSet<_>>();
+        let union = option.union(&input).count();
+        if union == 0 {
+            return 0.0;
+        }
+        let intersection = option.intersection(&input).count();
+        1.0 - intersection as f64 / union as f64
+    }
+}
+impl TokenSetRatio {
+    /// creates a new metric, that compares the words with respect to `case`
+    pub fn new(case: impl Into<CaseSensitivity>) -> Self {
+        Self { case: case.into() }
+    }
+    /// only copies `s`, when its case needs to be folded
+    fn fold_case(self, s: &str) -> Cow<'_, str> {
+        match self.case {
+            CaseSensitivity::Sensitive => Cow::Borrowed(s),
+            CaseSensitivity::Ascii => Cow::Owned(s.to_ascii_lowercase()),
+            #[cfg(feature = "unicode")]
+            CaseSensitivity::Unicode => {
+                Cow::Owned(s.chars().flat_map(CaseSensitivity::fold).collect())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// matches all options with a distance of at most `max_distance`
+pub struct Threshold<M> {
+    /// the metric used to calculate the distance
+    pub metric: M,
+    /// the maximal distance that still matches
+    pub max_distance: f64,
+}
+impl<M: StrMetric> StrFilter for Threshold<M> {
+    fn filter(&self, option: &str, input: &str) -> bool {
+        self.metric.distance(option, input) <= self.max_distance
+    }
+}
+
+#[cfg(feature = "unicode")]
+/// removes all diacritics from `s`, so "Jos\u{e9}" becomes "Jose"
+pub fn strip_accents(s: &str) -> String {
+    use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+    s.nfd().filter(|c| !is_combining_mark(*c)).nfc().collect()
+}
+#[cfg(feature = "unicode")]
+#[derive(Debug, Clone, Copy)]
+/// strips the diacritics of both strings before applying the original metric
+pub struct Normalized<O> {
+    /// the original metric
+    pub other: O,
+}
+#[cfg(feature = "unicode")]
+impl<O: StrMetric> StrMetric for Normalized<O> {
+    fn distance(&self, option: &str, input: &str) -> f64 {
+        self.other
+            .distance(&strip_accents(option), &strip_accents(input))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[derive(Debug)]
+    struct EndsWith;
+    impl StrFilter for EndsWith {
+        fn filter(&self, option: &str, input: &str) -> bool {
+            option.ends_with(input)
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        let options = ["aba", "abc", "cba", "xyz"];
+        let matching = |filter: &dyn StrFilter| {
+            options
+                .into_iter()
+                .filter(|it| filter.filter(it, "a"))
+                .collect_vec()
+        };
+        assert_eq!(
+            vec!["aba"],
+            matching(&StartsWithIgnoreCase.and(EndsWith)),
+            "and didn't require both"
+        );
+        assert_eq!(
+            vec!["aba", "abc", "cba"],
+            matching(&StartsWithIgnoreCase.or(EndsWith)),
+            "or didn't require either"
+        );
+    }
+
     fn __test_levenshtein(a: &str, b: &str, dist: usize, algo: Levenshtein) {
         let a = a.chars().collect_vec();
         let b = b.chars().collect_vec();
@@ -190,4 +557,371 @@ mod tests {
         __test_levenshtein("kitten", "sitting", 3, Levenshtein::new(false));
         __test_levenshtein("levENSHTein", "LEVENshtein", 6, Levenshtein::new(false));
     }
+    #[test]
+    fn test_levenshtein_edit_distance() {
+        let algo = Levenshtein::new(false);
+        assert_eq!(3, algo.edit_distance("kitten", "sitting"));
+        assert_eq!(3, algo.edit_distance("sitting", "kitten"));
+        assert_eq!(0, algo.edit_distance("kitten", "kitten"));
+        assert_eq!(6, algo.edit_distance("", "kitten"));
+        assert_eq!(0, Levenshtein::new(true).edit_distance("KitTen", "kitten"));
+    }
+
+    #[test]
+    fn test_levenshtein_costs() {
+        let rank = |algo: Levenshtein| {
+            sort_with(&algo, ["a", "axyd"], "abcd", |it| it)
+                .next()
+                .unwrap()
+        };
+        assert_eq!(
+            "axyd",
+            rank(Levenshtein::new(false)),
+            "failed default costs"
+        );
+        assert_eq!(
+            "a",
+            rank(Levenshtein::with_costs(false, 1, 1, 3)),
+            "didn't apply substitution cost"
+        );
+
+        let algo = Levenshtein::with_costs(false, 2, 3, 5);
+        assert_eq!(4, algo.edit_distance("ab", "abcd"), "failed insert cost");
+        assert_eq!(6, algo.edit_distance("abcd", "ab"), "failed delete cost");
+        assert_eq!(
+            5,
+            algo.edit_distance("abc", "abd"),
+            "failed substitute cost"
+        );
+        assert_eq!(
+            Some(5),
+            algo.bounded_distance("abc", "abd", 5),
+            "failed bounded costs"
+        );
+        assert_eq!(
+            None,
+            algo.bounded_distance("abc", "abd", 4),
+            "failed bounded cap"
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_bounded() {
+        let algo = Levenshtein::new(false);
+        let pairs = [
+            ("kitten", "sitting"),
+            ("Levenshtein", "Levenshtein"),
+            ("levENSHTein", "LEVENshtein"),
+            ("", "abc"),
+            ("abc", ""),
+            ("flaw", "lawn"),
+            ("intention", "execution"),
+        ];
+        for (a, b) in pairs {
+            let distance = algo.edit_distance(a, b);
+            for max in distance..distance + 3 {
+                assert_eq!(
+                    Some(distance),
+                    algo.bounded_distance(a, b, max),
+                    "failed for {a:?} and {b:?} with max {max}"
+                );
+            }
+            for max in 0..distance {
+                assert_eq!(
+                    None,
+                    algo.bounded_distance(a, b, max),
+                    "didn't cap {a:?} and {b:?} with max {max}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn starts_with_ignore_case() {
+        fn old(option: &str, input: &str) -> bool {
+            option.to_lowercase().starts_with(&input.to_lowercase())
+        }
+        let cases = [
+            ("Levenshtein", "lev"),
+            ("Levenshtein", "LEVENSHTEIN"),
+            ("Lev", "Levenshtein"),
+            ("Levenshtein", ""),
+            ("\u{c9}clair", "\u{e9}c"),
+            ("\u{e9}clair", "\u{c9}CL"),
+            ("\u{e9}clair", "ec"),
+            ("Stra\u{df}e", "STRA\u{df}"),
+            ("\u{130}stanbul", "i\u{307}s"),
+        ];
+        for (option, input) in cases {
+            assert_eq!(
+                old(option, input),
+                StartsWithIgnoreCase.filter(option, input),
+                "differend result for {option:?} and {input:?}"
+            );
+        }
+    }
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn unicode_case() {
+        const UPPER: &str = "\u{3a3}\u{39f}\u{3a3}";
+        const LOWER: &str = "\u{3c3}\u{3bf}\u{3c2}";
+        const TURKISH: &str = "\u{131}sparta";
+        let unicode = Levenshtein::with_case(CaseSensitivity::Unicode);
+        let ascii = Levenshtein::new(true);
+        assert_eq!(0, unicode.edit_distance(UPPER, LOWER), "failed greek");
+        assert_eq!(3, ascii.edit_distance(UPPER, LOWER), "folded greek");
+        assert_eq!(
+            Some(0),
+            unicode.bounded_distance(UPPER, LOWER, 1),
+            "failed bounded greek"
+        );
+        assert_eq!(
+            0,
+            unicode.edit_distance("ISPARTA", TURKISH),
+            "failed turkish"
+        );
+        assert_eq!(1, ascii.edit_distance("ISPARTA", TURKISH), "folded turkish");
+
+        let starts_with = |case| StartsWith { case }.filter(UPPER, &LOWER[..4]);
+        assert!(starts_with(CaseSensitivity::Unicode), "failed prefix");
+        assert!(!starts_with(CaseSensitivity::Ascii), "folded prefix");
+        assert!(
+            StartsWith {
+                case: CaseSensitivity::Unicode
+            }
+            .filter(UPPER, LOWER),
+            "failed final sigma"
+        );
+        assert!(
+            !StartsWithIgnoreCase.filter(UPPER, LOWER),
+            "StartsWithIgnoreCase changed"
+        );
+
+        let boost = |case| {
+            SameStartBoost::with_case(case, 0.5, Levenshtein::new(false)).distance(UPPER, LOWER)
+        };
+        assert!(
+            boost(CaseSensitivity::Unicode) < boost(CaseSensitivity::Ascii),
+            "didn't boost folded prefix"
+        );
+    }
+    #[test]
+    fn starts_with_modes() {
+        let filter = |case, option, input| StartsWith { case }.filter(option, input);
+        assert!(
+            filter(CaseSensitivity::Sensitive, "Lev", "Le"),
+            "failed sensitive"
+        );
+        assert!(
+            !filter(CaseSensitivity::Sensitive, "Lev", "le"),
+            "ignored case"
+        );
+        assert!(filter(CaseSensitivity::Ascii, "Lev", "lE"), "failed ascii");
+        assert!(
+            !filter(CaseSensitivity::Ascii, "Le", "lev"),
+            "failed long input"
+        );
+        #[cfg(feature = "unicode")]
+        assert!(
+            filter(CaseSensitivity::Unicode, "Lev", "lE"),
+            "failed unicode"
+        );
+    }
+    #[test]
+    fn starts_with_ignore_case_many() {
+        let options = (0..10_000)
+            .map(|i| format!("Option{i}-\u{c4}"))
+            .collect_vec();
+        for input in ["option1", "OPTION99", "option5-\u{e4}", "other"] {
+            let expected = options
+                .iter()
+                .filter(|it| it.to_lowercase().starts_with(&input.to_lowercase()))
+                .count();
+            let is = options
+                .iter()
+                .filter(|it| StartsWithIgnoreCase.filter(it, input))
+                .count();
+            assert_eq!(expected, is, "differend count for {input:?}");
+        }
+    }
+
+    #[cfg(feature = "fut_iter")]
+    #[test]
+    fn sort_stream() {
+        let stream = futures::stream::iter(["sitting", "kitten", "mitten", "written"]);
+        let sorted = futures::executor::block_on(super::sort_stream(
+            &Levenshtein::new(false),
+            stream,
+            "kitten",
+            |it| it,
+        ));
+        assert_eq!(
+            vec!["kitten", "mitten", "written", "sitting"],
+            sorted,
+            "sorted wrong"
+        );
+
+        let stream = futures::stream::iter(["mitten", "kitten"].map(std::rc::Rc::<str>::from));
+        let sorted = futures::executor::block_on(super::sort_stream(
+            &Levenshtein::new(false),
+            stream,
+            "kitten",
+            |it| it,
+        ));
+        assert_eq!(
+            vec!["kitten", "mitten"],
+            sorted.iter().map(AsRef::as_ref).collect_vec(),
+            "failed non Send stream"
+        );
+    }
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn match_ranges() {
+        let sensitive = Subsequence {
+            case: CaseSensitivity::Sensitive,
+        };
+        assert_eq!(
+            Some(vec![1..3]),
+            sensitive.match_ranges("aab", "ab"),
+            "failed contiguous match"
+        );
+        assert_eq!(
+            Some(vec![0..1, 5..7]),
+            sensitive.match_ranges("get_user", "gse"),
+            "failed scattered match"
+        );
+        assert_eq!(
+            None,
+            sensitive.match_ranges("get_user", "GU"),
+            "ignored case"
+        );
+        assert_eq!(
+            Some(vec![0..1, 4..5]),
+            Subsequence {
+                case: CaseSensitivity::Ascii
+            }
+            .match_ranges("get_user", "GU"),
+            "failed ignoring case"
+        );
+        assert_eq!(
+            Some(vec![2..5]),
+            sensitive.match_ranges("\u{e9}\u{e9}b", "\u{e9}b"),
+            "failed multibyte chars"
+        );
+        assert!(!sensitive.filter("abc", "ca"), "matched out of order");
+    }
+
+    #[test]
+    fn prefix_boost() {
+        let metric = Levenshtein::with_prefix_boost(true, 0.5);
+        assert_eq!(
+            vec!["ABxd", "xbcd", "xxxd"],
+            sort_with(&metric, ["xbcd", "xxxd", "ABxd"], "abcd", |it| it).collect_vec(),
+            "didn't rank prefix first"
+        );
+        let literal = SameStartBoost {
+            ignore_case: true,
+            same_start_bonus: 0.5,
+            other: Levenshtein::new(true),
+        };
+        assert!(
+            (literal.distance("ABxd", "abcd") - metric.distance("ABxd", "abcd")).abs()
+                < f64::EPSILON,
+            "differend distance with a bool"
+        );
+    }
+
+    #[test]
+    fn token_set_ratio() {
+        let metric = TokenSetRatio::new(CaseSensitivity::Sensitive);
+        assert!(
+            metric.distance("the quick fox", "fox  quick the").abs() < f64::EPSILON,
+            "failed reordered"
+        );
+        assert!(
+            (metric.distance("the quick fox", "lazy dog") - 1.0).abs() < f64::EPSILON,
+            "failed disjoint"
+        );
+        assert!(
+            (metric.distance("the quick fox", "the lazy fox") - 0.5).abs() < f64::EPSILON,
+            "failed partial"
+        );
+        assert!(
+            (metric.distance("The Fox", "the fox") - 1.0).abs() < f64::EPSILON,
+            "ignored case"
+        );
+        assert!(
+            TokenSetRatio::new(true)
+                .distance("The Fox", "fox the")
+                .abs()
+                < f64::EPSILON,
+            "failed to ignore case"
+        );
+        #[cfg(feature = "unicode")]
+        assert!(
+            TokenSetRatio::new(CaseSensitivity::Unicode)
+                .distance("STRASSE fox", "Fox stra\u{df}e")
+                .abs()
+                < f64::EPSILON,
+            "failed unicode case"
+        );
+        assert!(
+            (TokenSetRatio::new(true).distance("STRASSE", "stra\u{df}e") - 1.0).abs()
+                < f64::EPSILON,
+            "folded unicode in ascii mode"
+        );
+        assert!(
+            metric.distance("", " ").abs() < f64::EPSILON,
+            "failed empty"
+        );
+    }
+
+    #[test]
+    fn threshold() {
+        let tight = Threshold {
+            metric: Levenshtein::new(false),
+            max_distance: 0.2,
+        };
+        let loose = Threshold {
+            metric: Levenshtein::new(false),
+            max_distance: 0.5,
+        };
+        assert!(tight.filter("kitten", "kitten"), "tight rejected same word");
+        assert!(
+            !tight.filter("kitten", "sitting"),
+            "tight accepted fuzzy match"
+        );
+        assert!(
+            loose.filter("kitten", "sitting"),
+            "loose rejected fuzzy match"
+        );
+        assert!(
+            !loose.filter("kitten", "dog"),
+            "loose accepted differend word"
+        );
+        assert!(
+            loose.distance("kitten", "sitting") < f64::EPSILON,
+            "failed to use as metric"
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn normalized() {
+        assert_eq!("Jose", strip_accents("Jos\u{e9}"), "failed composed");
+        assert_eq!("Jose", strip_accents("Jose\u{301}"), "failed decomposed");
+        assert_eq!("\u{d55c}", strip_accents("\u{d55c}"), "changed hangul");
+
+        let metric = Normalized {
+            other: Levenshtein::new(false),
+        };
+        assert!(
+            metric.distance("Jos\u{e9}", "Jose").abs() < f64::EPSILON,
+            "accent made a differnce"
+        );
+        assert!(
+            metric.distance("Jos\u{e9}", "Jane") > 0.0,
+            "matched differend word"
+        );
+    }
 }